@@ -0,0 +1,178 @@
+//! Prometheus metrics derived directly from the core agent's [`ResourceStates`] registry.
+//!
+//! Unlike the periodic-refresh gauges in `grpc::operations::pool::metrics`, [`RegistryCollector`]
+//! implements [`Collector`] itself: the gauges are only (re)computed when the Prometheus registry
+//! is actually scraped, by taking a single `RwLock` read guard over [`ResourceStatesLocked`] and
+//! walking its `nexus_states()`/`pool_states()`/`replica_states()`/`snapshot_states()` iterators.
+//! This avoids the `*_states_cloned()` allocations on what would otherwise be a hot, unconditional
+//! refresh-timer path.
+
+use crate::controller::states::ResourceStatesLocked;
+use prometheus::{
+    core::{Collector, Desc},
+    proto::MetricFamily,
+    GaugeVec, IntGauge, IntGaugeVec, Opts,
+};
+
+/// Collector exposing cardinality, per-state breakdown and pool capacity metrics over the core
+/// agent's in-memory resource registry.
+pub(crate) struct RegistryCollector {
+    states: ResourceStatesLocked,
+    nexus_count: IntGauge,
+    pool_count: IntGauge,
+    replica_count: IntGauge,
+    snapshot_count: IntGauge,
+    nexus_by_status: IntGaugeVec,
+    replica_by_status: IntGaugeVec,
+    pool_capacity_bytes: IntGaugeVec,
+    pool_used_bytes: IntGaugeVec,
+    pool_free_bytes: IntGaugeVec,
+    pool_overcommit_ratio: GaugeVec,
+}
+
+impl RegistryCollector {
+    /// Create a new collector over the given (already shared) resource registry.
+    pub(crate) fn new(states: ResourceStatesLocked) -> prometheus::Result<Self> {
+        Ok(Self {
+            states,
+            nexus_count: IntGauge::with_opts(Opts::new(
+                "registry_nexus_count",
+                "Number of nexuses known to the core agent's registry",
+            ))?,
+            pool_count: IntGauge::with_opts(Opts::new(
+                "registry_pool_count",
+                "Number of pools known to the core agent's registry",
+            ))?,
+            replica_count: IntGauge::with_opts(Opts::new(
+                "registry_replica_count",
+                "Number of replicas known to the core agent's registry",
+            ))?,
+            snapshot_count: IntGauge::with_opts(Opts::new(
+                "registry_snapshot_count",
+                "Number of replica snapshots known to the core agent's registry",
+            ))?,
+            nexus_by_status: IntGaugeVec::new(
+                Opts::new(
+                    "registry_nexus_status_count",
+                    "Number of nexuses, broken down by their NexusState status",
+                ),
+                &["status"],
+            )?,
+            replica_by_status: IntGaugeVec::new(
+                Opts::new(
+                    "registry_replica_status_count",
+                    "Number of replicas, broken down by their ReplicaState status",
+                ),
+                &["status"],
+            )?,
+            pool_capacity_bytes: IntGaugeVec::new(
+                Opts::new("registry_pool_capacity_bytes", "Pool capacity in bytes"),
+                &["pool", "node"],
+            )?,
+            pool_used_bytes: IntGaugeVec::new(
+                Opts::new("registry_pool_used_bytes", "Pool used bytes"),
+                &["pool", "node"],
+            )?,
+            pool_free_bytes: IntGaugeVec::new(
+                Opts::new("registry_pool_free_bytes", "Pool free bytes"),
+                &["pool", "node"],
+            )?,
+            pool_overcommit_ratio: GaugeVec::new(
+                Opts::new(
+                    "registry_pool_overcommit_ratio",
+                    "Pool used/capacity ratio (1.0 == full)",
+                ),
+                &["pool", "node"],
+            )?,
+        })
+    }
+
+    /// Register `self` with the default Prometheus registry.
+    pub(crate) fn register(states: ResourceStatesLocked) -> prometheus::Result<()> {
+        prometheus::register(Box::new(Self::new(states)?))
+    }
+}
+
+impl Collector for RegistryCollector {
+    fn desc(&self) -> Vec<&Desc> {
+        self.nexus_count
+            .desc()
+            .into_iter()
+            .chain(self.pool_count.desc())
+            .chain(self.replica_count.desc())
+            .chain(self.snapshot_count.desc())
+            .chain(self.nexus_by_status.desc())
+            .chain(self.replica_by_status.desc())
+            .chain(self.pool_capacity_bytes.desc())
+            .chain(self.pool_used_bytes.desc())
+            .chain(self.pool_free_bytes.desc())
+            .chain(self.pool_overcommit_ratio.desc())
+            .collect()
+    }
+
+    fn collect(&self) -> Vec<MetricFamily> {
+        // A single read guard covers every gauge below, so the whole scrape observes one
+        // consistent snapshot of the registry rather than drifting between gauges.
+        let states = self.states.read();
+
+        self.nexus_by_status.reset();
+        self.nexus_count.set(states.nexus_states().len() as i64);
+        for nexus in states.nexus_states() {
+            let nexus = nexus.lock();
+            self.nexus_by_status
+                .with_label_values(&[&nexus.status.to_string()])
+                .inc();
+        }
+
+        self.replica_by_status.reset();
+        self.replica_count.set(states.replica_states().len() as i64);
+        for replica in states.replica_states() {
+            let replica = replica.lock();
+            self.replica_by_status
+                .with_label_values(&[&replica.status.to_string()])
+                .inc();
+        }
+
+        self.pool_capacity_bytes.reset();
+        self.pool_used_bytes.reset();
+        self.pool_free_bytes.reset();
+        self.pool_overcommit_ratio.reset();
+        self.pool_count.set(states.pool_states().len() as i64);
+        for pool in states.pool_states() {
+            let pool = pool.lock();
+            let labels: [&str; 2] = [pool.id.as_str(), pool.node.as_str()];
+            self.pool_capacity_bytes
+                .with_label_values(&labels)
+                .set(pool.capacity as i64);
+            self.pool_used_bytes
+                .with_label_values(&labels)
+                .set(pool.used as i64);
+            let free = pool.capacity.saturating_sub(pool.used);
+            self.pool_free_bytes.with_label_values(&labels).set(free as i64);
+            let overcommit = if pool.capacity == 0 {
+                0.0
+            } else {
+                pool.used as f64 / pool.capacity as f64
+            };
+            self.pool_overcommit_ratio
+                .with_label_values(&labels)
+                .set(overcommit);
+        }
+
+        self.snapshot_count.set(states.snapshot_states().len() as i64);
+
+        self.nexus_count
+            .collect()
+            .into_iter()
+            .chain(self.pool_count.collect())
+            .chain(self.replica_count.collect())
+            .chain(self.snapshot_count.collect())
+            .chain(self.nexus_by_status.collect())
+            .chain(self.replica_by_status.collect())
+            .chain(self.pool_capacity_bytes.collect())
+            .chain(self.pool_used_bytes.collect())
+            .chain(self.pool_free_bytes.collect())
+            .chain(self.pool_overcommit_ratio.collect())
+            .collect()
+    }
+}
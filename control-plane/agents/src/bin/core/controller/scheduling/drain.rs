@@ -0,0 +1,114 @@
+//! Gradual, rate-limited replica migration off a draining node.
+//!
+//! Cordoning a node is abrupt: it stops new placement immediately but leaves the node's existing
+//! replicas right where they are. Draining is the gentler decommissioning counterpart used for
+//! node retirement or disk replacement: [`NodeFilters::draining`](super::NodeFilters::draining)/
+//! [`draining_for_pool`](super::NodeFilters::draining_for_pool) keep the node out of *new*
+//! placement, while [`DrainReconciler`] walks its existing replicas off a few at a time, reusing
+//! [`RebalanceSolver`] so every move still honours the volume's topology/anti-affinity
+//! constraints via whatever `candidate_pools` the normal suitable-pools pipeline already narrowed
+//! down to.
+
+use super::{
+    rebalance::{CurrentPools, RebalanceSolver},
+    resources::PoolItem,
+};
+use std::collections::HashSet;
+use stor_port::types::v0::transport::{PoolId, ReplicaId};
+
+/// How many of a draining node's replicas are still left to move off it, for operators polling
+/// whether a node is safe to remove.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DrainProgress {
+    pub(crate) replicas_remaining: usize,
+}
+
+impl DrainProgress {
+    /// A node is safe to remove once it has no replicas left to move.
+    pub(crate) fn is_complete(&self) -> bool {
+        self.replicas_remaining == 0
+    }
+}
+
+/// Caps how many replica moves a draining node may have in flight at once, so that draining
+/// doesn't spike rebuild traffic across the cluster the way cordoning followed by a bulk
+/// migration would.
+pub(crate) struct DrainReconciler {
+    max_concurrent_moves: usize,
+    in_flight: HashSet<ReplicaId>,
+}
+
+impl DrainReconciler {
+    /// Create a new `Self`, allowing at most `max_concurrent_moves` replica moves in flight at
+    /// once (clamped to at least one, so draining always makes forward progress).
+    pub(crate) fn new(max_concurrent_moves: usize) -> Self {
+        Self {
+            max_concurrent_moves: max_concurrent_moves.max(1),
+            in_flight: HashSet::new(),
+        }
+    }
+
+    /// Report drain progress for a node from the replicas it still hosts.
+    pub(crate) fn progress(node_replicas: &[ReplicaId]) -> DrainProgress {
+        DrainProgress {
+            replicas_remaining: node_replicas.len(),
+        }
+    }
+
+    /// How many additional moves can start right now without exceeding the concurrency limit.
+    fn available_slots(&self) -> usize {
+        self.max_concurrent_moves.saturating_sub(self.in_flight.len())
+    }
+
+    /// Pick the next batch of the draining node's replicas to move elsewhere, honouring both the
+    /// concurrency limit and the volume's topology/anti-affinity constraints (already baked into
+    /// `candidate_pools` by the usual `NodeFilters`/`PoolBaseFilters` pipeline). Returns the
+    /// `(replica, destination pool)` pairs to act on. If the solver cannot find a destination for
+    /// every one of `draining_replicas`, no moves are returned at all and the whole set is tried
+    /// again on a later tick.
+    pub(crate) fn plan_moves(
+        &mut self,
+        draining_replicas: &[(ReplicaId, PoolId)],
+        candidate_pools: &[PoolItem],
+        replica_size: u64,
+    ) -> Vec<(ReplicaId, PoolId)> {
+        let slots = self.available_slots();
+        if slots == 0 || draining_replicas.is_empty() {
+            return Vec::new();
+        }
+
+        let current: CurrentPools = draining_replicas
+            .iter()
+            .map(|(_, pool)| pool.clone())
+            .collect();
+        let Some(plan) = RebalanceSolver::solve(
+            candidate_pools,
+            draining_replicas.len(),
+            replica_size,
+            &current,
+        ) else {
+            return Vec::new();
+        };
+
+        draining_replicas
+            .iter()
+            .zip(plan.assignment.iter())
+            .filter(|((_, from_pool), to_pool)| *from_pool != *to_pool)
+            .filter(|((replica, _), _)| !self.in_flight.contains(replica))
+            .take(slots)
+            .map(|((replica, _), to_pool)| (replica.clone(), to_pool.clone()))
+            .collect()
+    }
+
+    /// Mark `replica` as having a move in flight, consuming one concurrency slot until
+    /// [`Self::complete_move`] is called for it.
+    pub(crate) fn begin_move(&mut self, replica: ReplicaId) {
+        self.in_flight.insert(replica);
+    }
+
+    /// Mark a previously started move as finished, whether it succeeded or failed, freeing its
+    /// slot back up for the next tick.
+    pub(crate) fn complete_move(&mut self, replica: &ReplicaId) {
+        self.in_flight.remove(replica);
+    }
+}
@@ -1,6 +1,9 @@
 pub(crate) mod affinity_group;
+pub(crate) mod drain;
 pub(crate) mod nexus;
+pub(crate) mod placement;
 pub(crate) mod pool;
+pub(crate) mod rebalance;
 pub(crate) mod resources;
 pub(crate) mod volume;
 mod volume_policy;
@@ -188,6 +191,19 @@ impl NodeFilters {
             .any(|node_spec| node_spec.id() == &item.pool.node)
     }
 
+    /// Should only attempt to use nodes which are not draining. Unlike cordon, draining is not
+    /// simply rejected here: [`drain::DrainReconciler`] is what actually moves the node's
+    /// existing replicas elsewhere, in the background and rate-limited. This filter only keeps a
+    /// draining node out of *new* replica placement while that's under way.
+    pub(crate) fn draining_for_pool(request: &GetSuitablePoolsContext, item: &PoolItem) -> bool {
+        let registry = request.registry();
+        !registry
+            .specs()
+            .draining_nodes()
+            .into_iter()
+            .any(|node_spec| node_spec.id() == &item.pool.node)
+    }
+
     /// Should only attempt to use online nodes.
     pub(crate) fn online(_request: &GetSuitableNodesContext, item: &NodeItem) -> bool {
         item.node_wrapper().is_online()
@@ -203,6 +219,17 @@ impl NodeFilters {
             .any(|node_spec| node_spec.id() == item.node_wrapper().id())
     }
 
+    /// Should only attempt to use nodes which are not draining, for new nexus/target placement.
+    /// See [`Self::draining_for_pool`] for the replica-placement counterpart.
+    pub(crate) fn draining(request: &GetSuitableNodesContext, item: &NodeItem) -> bool {
+        let registry = request.registry();
+        !registry
+            .specs()
+            .draining_nodes()
+            .into_iter()
+            .any(|node_spec| node_spec.id() == item.node_wrapper().id())
+    }
+
     /// Should only attempt to use node where current target is not present.
     pub(crate) fn current_target(request: &GetSuitableNodesContext, item: &NodeItem) -> bool {
         if let Some(target) = request.target() {
@@ -213,7 +240,6 @@ impl NodeFilters {
     }
     /// Should only attempt to use nodes having specific creation label if topology has it.
     pub(crate) fn topology(request: &GetSuitableNodesContext, item: &NodeItem) -> bool {
-        println!("ASHISH");
         let volume_node_topology_inclusion_labels: HashMap<String, String>;
         let volume_node_topology_exclusion_labels: HashMap<String, String>;
         match request.topology.clone() {
@@ -240,7 +266,20 @@ impl NodeFilters {
                             return true;
                         }
                     }
-                    NodeTopology::Explicit(_) => todo!(),
+                    // Hard constraint: a non-empty allow-list rejects every node outside it.
+                    // `preferred_nodes` is a soft hint and is left to the sorters, not enforced
+                    // here.
+                    NodeTopology::Explicit(explicit) => {
+                        return explicit.allowed_nodes.is_empty()
+                            || explicit
+                                .allowed_nodes
+                                .contains(item.node_wrapper().id());
+                    }
+                    // The domain spread itself is enforced pool-side by
+                    // `PoolBaseFilters::spread_topology` and `PoolBaseFilters::domain_spread`,
+                    // since both need to see every remaining candidate at once to cap replicas
+                    // per domain; nothing to reject per-node.
+                    NodeTopology::Spread { .. } => return true,
                 },
             },
         };
@@ -252,16 +291,15 @@ impl NodeFilters {
             .node(&item.node_wrapper.node_state.id())
         {
             Ok(spec) => {
-                // Inclusion condition
                 let inc_qualify = does_node_qualify_inclusion_labels(
                     volume_node_topology_inclusion_labels,
+                    spec.labels.clone(),
+                );
+                let exc_qualify = does_node_qualify_exclusion_labels(
+                    volume_node_topology_exclusion_labels,
                     spec.labels,
                 );
-                // let exc_qualify = does_node_qualify_exclusion_labels(
-                //     volume_node_topology_exclusion_labels,
-                //     spec.labels,
-                // );
-                return inc_qualify;
+                inc_qualify && exc_qualify
             }
             Err(_) => false,
         }
@@ -301,30 +339,22 @@ pub(crate) fn does_node_qualify_inclusion_labels(
     inc_match
 }
 
-
-// /// Retruns true if all the keys in volume inclusive labels
-// /// matches to the node labels; otherwise returns false
-// pub(crate) fn does_node_qualify_exclusion_labels(
-//     vol_exc_labels: HashMap<String, String>,
-//     node_labels: HashMap<String, String>,
-// ) -> bool {
-//     let mut inc_match = true; // Initialize to true, assuming inclusive match until proven otherwise
-//     for (vol_inc_key, vol_inc_value) in vol_exc_labels.iter() {
-//         match node_labels.get(vol_inc_key) {
-//             Some(node_val) => {
-//                 if node_val != vol_inc_value {
-//                     inc_match = false;
-//                     break; // No need to continue checking once a mismatch is found
-//                 }
-//             }
-//             None => {
-//                 inc_match = false;
-//                 break; // No need to continue checking if a key is not present
-//             }
-//         }
-//     }
-//     inc_match
-// }
+/// Returns false if any exclusion key/value pair is present on the node; otherwise true. Unlike
+/// inclusion, a key the node simply doesn't carry is not a mismatch: exclusion only rejects nodes
+/// that actively carry the excluded value.
+pub(crate) fn does_node_qualify_exclusion_labels(
+    vol_exc_labels: HashMap<String, String>,
+    node_labels: HashMap<String, String>,
+) -> bool {
+    for (vol_exc_key, vol_exc_value) in vol_exc_labels.iter() {
+        if let Some(node_val) = node_labels.get(vol_exc_key) {
+            if node_val == vol_exc_value {
+                return false;
+            }
+        }
+    }
+    true
+}
 
 /// Sort the nexus children for removal when decreasing a volume's replica count
 pub(crate) struct ChildSorters {}
@@ -500,6 +530,8 @@ impl AddReplicaSorters {
     /// 1. replicas local to the nexus
     /// 2. replicas which have not been marked as faulted by the io-engine
     /// 3. replicas from pools with more free space
+    ///
+    /// A node's online/offline history is not taken into account.
     pub(crate) fn sort(
         request: &VolumeReplicasForNexusCtx,
         a: &ChildItem,
@@ -541,3 +573,93 @@ impl NodeSorters {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{does_node_qualify_exclusion_labels, does_node_qualify_inclusion_labels};
+    use std::collections::HashMap;
+
+    fn labels(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn inclusion_only_qualifies_matching_node() {
+        let inclusion = labels(&[("zone", "a")]);
+        let matching_node = labels(&[("zone", "a"), ("rack", "1")]);
+        let mismatched_node = labels(&[("zone", "b")]);
+        let missing_key_node = labels(&[("rack", "1")]);
+
+        assert!(does_node_qualify_inclusion_labels(
+            inclusion.clone(),
+            matching_node
+        ));
+        assert!(!does_node_qualify_inclusion_labels(
+            inclusion.clone(),
+            mismatched_node
+        ));
+        assert!(!does_node_qualify_inclusion_labels(
+            inclusion,
+            missing_key_node
+        ));
+    }
+
+    #[test]
+    fn exclusion_only_rejects_only_matching_node() {
+        let exclusion = labels(&[("zone", "a")]);
+        let excluded_node = labels(&[("zone", "a")]);
+        let other_value_node = labels(&[("zone", "b")]);
+        let missing_key_node = labels(&[("rack", "1")]);
+
+        assert!(!does_node_qualify_exclusion_labels(
+            exclusion.clone(),
+            excluded_node
+        ));
+        assert!(does_node_qualify_exclusion_labels(
+            exclusion.clone(),
+            other_value_node
+        ));
+        assert!(does_node_qualify_exclusion_labels(
+            exclusion,
+            missing_key_node
+        ));
+    }
+
+    #[test]
+    fn combined_inclusion_and_exclusion() {
+        let inclusion = labels(&[("zone", "a")]);
+        let exclusion = labels(&[("rack", "bad")]);
+        let qualifying_node = labels(&[("zone", "a"), ("rack", "good")]);
+        let excluded_node = labels(&[("zone", "a"), ("rack", "bad")]);
+        let non_included_node = labels(&[("zone", "b"), ("rack", "good")]);
+
+        assert!(
+            does_node_qualify_inclusion_labels(inclusion.clone(), qualifying_node.clone())
+                && does_node_qualify_exclusion_labels(exclusion.clone(), qualifying_node)
+        );
+        assert!(
+            does_node_qualify_inclusion_labels(inclusion.clone(), excluded_node.clone())
+                && !does_node_qualify_exclusion_labels(exclusion.clone(), excluded_node)
+        );
+        assert!(
+            !does_node_qualify_inclusion_labels(inclusion, non_included_node.clone())
+                || !does_node_qualify_exclusion_labels(exclusion, non_included_node)
+        );
+    }
+
+    #[test]
+    fn explicit_inclusion_exclusion_sets_are_unsatisfiable() {
+        // Same key required by inclusion and forbidden by exclusion with the same value: no
+        // node's labels can satisfy both constraints at once.
+        let inclusion = labels(&[("zone", "a")]);
+        let exclusion = labels(&[("zone", "a")]);
+        let node = labels(&[("zone", "a")]);
+
+        let qualifies = does_node_qualify_inclusion_labels(inclusion, node.clone())
+            && does_node_qualify_exclusion_labels(exclusion, node);
+        assert!(!qualifies);
+    }
+}
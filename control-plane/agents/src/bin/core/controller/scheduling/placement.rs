@@ -0,0 +1,389 @@
+use crate::controller::states::ResourceStates;
+use agents::errors::NotEnough;
+use std::collections::{HashMap, HashSet, VecDeque};
+use stor_port::types::v0::transport::{PoolId, PoolState, ReplicaId, VolumeId};
+
+/// Label used to derive a pool's failure domain (eg rack/zone) for replica spreading.
+/// Pools without this label fall back to using their node as their own, single-pool domain.
+const ZONE_LABEL: &str = "topology.kubernetes.io/zone";
+
+/// A replica which still needs a pool assigned to it.
+#[derive(Debug, Clone)]
+pub(crate) struct ReplicaSlot {
+    replica: ReplicaId,
+}
+impl ReplicaSlot {
+    /// Create a new slot for the given (already allocated) replica identifier.
+    pub(crate) fn new(replica: ReplicaId) -> Self {
+        Self { replica }
+    }
+}
+
+/// Computes a min-cost max-flow assignment of a volume's replica slots to pools.
+///
+/// The network is: `source -> slot -> zone -> pool -> sink`, where a slot may only reach a zone
+/// not already occupied by one of the volume's other replicas, and a pool's edge to the sink is
+/// capacity-bound by its spare capacity and costed by its resulting utilisation. This prefers
+/// emptier pools over fuller ones while still maximising the number of slots placed and spreading
+/// them across failure domains.
+pub(crate) struct ReplicaPlacementEngine<'a> {
+    states: &'a ResourceStates,
+}
+
+impl<'a> ReplicaPlacementEngine<'a> {
+    /// Create a new engine operating over the given (already cloned) resource state snapshot.
+    pub(crate) fn new(states: &'a ResourceStates) -> Self {
+        Self { states }
+    }
+
+    /// Place the given replica `slots` of `volume` onto pools, honouring `used_zones` which are
+    /// the failure domains already occupied by the volume's existing replicas.
+    pub(crate) fn place(
+        &self,
+        volume: &VolumeId,
+        slots: &[ReplicaSlot],
+        replica_size: u64,
+        used_zones: &HashSet<String>,
+    ) -> Result<Vec<(ReplicaId, PoolId)>, NotEnough> {
+        let pools = self.states.pool_states_cloned();
+        let graph = FlowGraph::new(slots, &pools, replica_size, used_zones);
+        let assignment = graph.min_cost_max_flow();
+        tracing::trace!(%volume, placed = assignment.len(), requested = slots.len(), "replica placement");
+
+        if assignment.len() < slots.len() {
+            let domains = pools
+                .iter()
+                .map(Self::zone_of)
+                .filter(|zone| !used_zones.contains(zone))
+                .collect::<HashSet<_>>()
+                .len() as u64;
+            return if (domains as usize) < slots.len() {
+                Err(NotEnough::ReplicaSpread {
+                    have: assignment.len() as u64,
+                    need: slots.len() as u64,
+                    domains,
+                })
+            } else {
+                Err(NotEnough::ReplicaCapacity {
+                    have: assignment.len() as u64,
+                    need: slots.len() as u64,
+                    available: pools
+                        .iter()
+                        .map(|pool| pool_free_space(pool) / replica_size.max(1))
+                        .sum(),
+                })
+            };
+        }
+
+        Ok(assignment)
+    }
+
+    /// Rank candidate `pools` by the same min-cost zone-spread objective [`Self::place`] uses, for
+    /// `replica_count` slots still to be filled, without needing already-allocated replica ids.
+    ///
+    /// Called from [`super::volume_policy::pool::PoolBaseFilters`] to order pool-selection
+    /// candidates by the solver's preference instead of `domain_spread`'s fixed per-domain cap
+    /// alone, so multi-replica volume creation actually benefits from the flow-based spread this
+    /// engine computes. Pools the solver didn't reach (eg already full, or whose zone is already
+    /// used) are left out; the caller keeps its own fallback ordering for the remainder.
+    pub(crate) fn rank_pools(
+        pools: &[PoolState],
+        replica_count: usize,
+        replica_size: u64,
+        used_zones: &HashSet<String>,
+    ) -> Vec<PoolId> {
+        if replica_count == 0 || pools.is_empty() {
+            return Vec::new();
+        }
+        let mut graph = FlowGraph::new_ranking(replica_count, pools, replica_size, used_zones);
+        graph.solve();
+        graph.extract_pool_order(replica_count)
+    }
+
+    /// Derive the failure domain of a pool: its zone label if set, otherwise its own node, so
+    /// that pools without zone information are each treated as their own isolated domain.
+    fn zone_of(pool: &PoolState) -> String {
+        pool.labels
+            .as_ref()
+            .and_then(|labels| labels.get(ZONE_LABEL))
+            .cloned()
+            .unwrap_or_else(|| format!("node/{}", pool.node))
+    }
+}
+
+/// Spare capacity of a pool, in bytes, available for new replicas.
+fn pool_free_space(pool: &PoolState) -> u64 {
+    pool.capacity.saturating_sub(pool.used)
+}
+
+/// A directed edge in the residual graph of the flow network.
+#[derive(Debug, Clone, Copy)]
+struct Edge {
+    to: usize,
+    cap: i64,
+    cost: i64,
+}
+
+/// Flow network built up from replica slots, failure domains and pools, solved via successive
+/// shortest augmenting paths (Bellman-Ford/SPFA, since the reverse residual edges carry negative
+/// costs once flow has been pushed along their forward edges).
+struct FlowGraph {
+    /// Adjacency list of edges; edge `i` and its reverse residual edge are stored as `i^1`.
+    edges: Vec<Edge>,
+    adj: Vec<Vec<usize>>,
+    source: usize,
+    sink: usize,
+    // Node index ranges, used to classify an edge's endpoint without guessing from residual
+    // capacities: `[slot_base, zone_base)` are replica slots, `[zone_base, pool_base)` are
+    // failure domains and `[pool_base, sink)` are pools.
+    slot_base: usize,
+    zone_base: usize,
+    pool_base: usize,
+    /// One entry per slot; `None` for a [`Self::new_ranking`] graph, which only cares which pools
+    /// the solver reaches, not which replica ends up on them.
+    slot_replicas: Vec<Option<ReplicaId>>,
+    pool_ids: Vec<PoolId>,
+}
+
+impl FlowGraph {
+    fn new(
+        slots: &[ReplicaSlot],
+        pools: &[PoolState],
+        replica_size: u64,
+        used_zones: &HashSet<String>,
+    ) -> Self {
+        let slot_replicas = slots.iter().map(|slot| Some(slot.replica.clone())).collect();
+        Self::build(slots.len(), slot_replicas, pools, replica_size, used_zones)
+    }
+
+    /// Build a graph for [`ReplicaPlacementEngine::rank_pools`], which only needs pool ranking and
+    /// has no already-allocated replica ids to attach to each slot.
+    fn new_ranking(
+        n_slots: usize,
+        pools: &[PoolState],
+        replica_size: u64,
+        used_zones: &HashSet<String>,
+    ) -> Self {
+        Self::build(n_slots, vec![None; n_slots], pools, replica_size, used_zones)
+    }
+
+    fn build(
+        n_slots: usize,
+        slot_replicas: Vec<Option<ReplicaId>>,
+        pools: &[PoolState],
+        replica_size: u64,
+        used_zones: &HashSet<String>,
+    ) -> Self {
+        let zones = pools
+            .iter()
+            .map(ReplicaPlacementEngine::zone_of)
+            .collect::<Vec<_>>();
+        let distinct_zones = zones
+            .iter()
+            .cloned()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let zone_index = distinct_zones
+            .iter()
+            .enumerate()
+            .map(|(i, zone)| (zone.clone(), i))
+            .collect::<HashMap<_, _>>();
+
+        let source = 0;
+        let slot_base = 1;
+        let zone_base = slot_base + n_slots;
+        let pool_base = zone_base + distinct_zones.len();
+        let sink = pool_base + pools.len();
+
+        let mut graph = FlowGraph {
+            edges: Vec::new(),
+            adj: vec![Vec::new(); sink + 1],
+            source,
+            sink,
+            slot_base,
+            zone_base,
+            pool_base,
+            slot_replicas,
+            pool_ids: pools.iter().map(|pool| pool.id.clone()).collect(),
+        };
+
+        for i in 0..n_slots {
+            graph.add_edge(source, slot_base + i, 1, 0);
+        }
+        for (i, zone) in distinct_zones.iter().enumerate() {
+            if used_zones.contains(zone) {
+                continue;
+            }
+            for s in 0..n_slots {
+                graph.add_edge(slot_base + s, zone_base + i, 1, 0);
+            }
+        }
+        for (p, pool) in pools.iter().enumerate() {
+            let zone = &zones[p];
+            if used_zones.contains(zone) {
+                continue;
+            }
+            let Some(&z) = zone_index.get(zone) else {
+                continue;
+            };
+            graph.add_edge(zone_base + z, pool_base + p, 1, 0);
+
+            let free_slots = (pool_free_space(pool) / replica_size.max(1)).min(n_slots as u64);
+            for slot in 0..free_slots {
+                // Each unit of flow into this pool represents one more replica landing there;
+                // the cost grows with the resulting used-fraction so emptier pools are cheaper.
+                let used_after = pool.used.saturating_add((slot + 1) * replica_size);
+                let cost = Self::utilisation_cost(used_after, pool.capacity);
+                graph.add_edge(pool_base + p, sink, 1, cost);
+            }
+        }
+
+        graph
+    }
+
+    /// Cost proportional to the post-placement used-fraction of the pool, scaled up so it can be
+    /// represented as an integer edge cost.
+    fn utilisation_cost(used_after: u64, capacity: u64) -> i64 {
+        const SCALE: u64 = 1_000_000;
+        if capacity == 0 {
+            return SCALE as i64;
+        }
+        ((used_after.min(capacity) as u128 * SCALE as u128) / capacity as u128) as i64
+    }
+
+    fn add_edge(&mut self, from: usize, to: usize, cap: i64, cost: i64) {
+        let forward = self.edges.len();
+        self.edges.push(Edge { to, cap, cost });
+        self.adj[from].push(forward);
+
+        let backward = self.edges.len();
+        self.edges.push(Edge {
+            to: from,
+            cap: 0,
+            cost: -cost,
+        });
+        self.adj[to].push(backward);
+    }
+
+    /// Successive shortest paths min-cost max-flow, returning the (replica, pool) assignment
+    /// implied by the saturated `pool -> sink` edges.
+    fn min_cost_max_flow(&mut self) -> Vec<(ReplicaId, PoolId)> {
+        self.solve();
+        self.extract_assignment()
+    }
+
+    /// Run successive shortest augmenting paths to saturate the network, without extracting the
+    /// resulting assignment (see [`Self::extract_assignment`]/[`Self::extract_pool_order`]).
+    fn solve(&mut self) {
+        loop {
+            let Some((dist, prev_edge)) = self.shortest_path() else {
+                break;
+            };
+            if dist[self.sink] == i64::MAX {
+                break;
+            }
+
+            // Every augmenting path here has capacity exactly 1 (source/slot edges are unit
+            // capacity), so each successful iteration places exactly one replica.
+            let mut v = self.sink;
+            while v != self.source {
+                let e = prev_edge[v].expect("reachable node has an incoming edge");
+                self.edges[e].cap -= 1;
+                self.edges[e ^ 1].cap += 1;
+                v = self.edges[e ^ 1].to;
+            }
+        }
+    }
+
+    /// Bellman-Ford/SPFA shortest path search over the residual graph from `source`.
+    fn shortest_path(&self) -> Option<(Vec<i64>, Vec<Option<usize>>)> {
+        let n = self.adj.len();
+        let mut dist = vec![i64::MAX; n];
+        let mut prev_edge: Vec<Option<usize>> = vec![None; n];
+        let mut in_queue = vec![false; n];
+        dist[self.source] = 0;
+
+        let mut queue = VecDeque::new();
+        queue.push_back(self.source);
+        in_queue[self.source] = true;
+
+        while let Some(u) = queue.pop_front() {
+            in_queue[u] = false;
+            if dist[u] == i64::MAX {
+                continue;
+            }
+            for &e in &self.adj[u] {
+                let edge = self.edges[e];
+                if edge.cap <= 0 {
+                    continue;
+                }
+                let next = dist[u] + edge.cost;
+                if next < dist[edge.to] {
+                    dist[edge.to] = next;
+                    prev_edge[edge.to] = Some(e);
+                    if !in_queue[edge.to] {
+                        queue.push_back(edge.to);
+                        in_queue[edge.to] = true;
+                    }
+                }
+            }
+        }
+
+        if dist[self.sink] == i64::MAX {
+            None
+        } else {
+            Some((dist, prev_edge))
+        }
+    }
+
+    fn extract_assignment(&self) -> Vec<(ReplicaId, PoolId)> {
+        self.slots_to_pools(self.slot_replicas.len())
+            .into_iter()
+            .zip(self.slot_replicas.iter())
+            .filter_map(|(pool, replica)| {
+                pool.zip(replica.clone()).map(|(pool, replica)| (replica, pool))
+            })
+            .collect()
+    }
+
+    /// The pool each of the first `n_slots` slots was routed to (`None` for a slot the solver
+    /// didn't manage to place), in slot order. Exposed via [`Self::extract_pool_order`] for
+    /// [`ReplicaPlacementEngine::rank_pools`], which has no replica ids to pair the pools with.
+    fn slots_to_pools(&self, n_slots: usize) -> Vec<Option<PoolId>> {
+        // A zone can carry flow to several of its pools, so each saturated `zone -> pool` edge is
+        // claimed by exactly one slot.
+        let mut claimed = HashSet::new();
+        (0..n_slots)
+            .map(|slot| {
+                let slot_node = self.slot_base + slot;
+                let zone_node =
+                    self.saturated_target(slot_node, self.zone_base, self.pool_base)?;
+                let (edge, pool_node) = self.adj[zone_node].iter().copied().find_map(|e| {
+                    let edge = self.edges[e];
+                    (edge.cap == 0
+                        && edge.to >= self.pool_base
+                        && edge.to < self.sink
+                        && !claimed.contains(&e))
+                    .then_some((e, edge.to))
+                })?;
+                claimed.insert(edge);
+                Some(self.pool_ids[pool_node - self.pool_base].clone())
+            })
+            .collect()
+    }
+
+    /// The pools the solver reached, most-preferred first, skipping slots it couldn't place.
+    fn extract_pool_order(&self, n_slots: usize) -> Vec<PoolId> {
+        self.slots_to_pools(n_slots).into_iter().flatten().collect()
+    }
+
+    /// Find the (single) forward edge out of `node` whose target falls in `[lo, hi)` and which
+    /// carried flow (its unit capacity has been consumed down to zero).
+    fn saturated_target(&self, node: usize, lo: usize, hi: usize) -> Option<usize> {
+        self.adj[node].iter().copied().find_map(|e| {
+            let edge = self.edges[e];
+            (edge.cap == 0 && edge.to >= lo && edge.to < hi).then_some(edge.to)
+        })
+    }
+}
@@ -0,0 +1,308 @@
+use super::resources::PoolItem;
+use std::collections::{HashSet, VecDeque};
+use stor_port::types::v0::transport::PoolId;
+
+/// Computes a replica-to-pool assignment that minimises the number of replicas which must move
+/// off their current pool, feeding a more stable result back into the greedy
+/// [`NodeFilters`](super::NodeFilters)/[`SortBuilder`](super::SortBuilder) pipeline than picking
+/// pools from scratch on every call would.
+///
+/// The network is: `source -> slot -> node -> pool -> sink`. Every `slot` represents one of the
+/// volume's `replica_count` replicas; the `node` layer is split into an in/out pair joined by a
+/// capacity-1 edge so that, whatever pools it offers, a node ends up hosting at most one replica
+/// of the volume. Every `node -> pool` edge is costed `0` if the pool is already occupied by the
+/// volume, `1` otherwise, so that an optimal (min-cost) assignment is also one with minimal data
+/// movement. Every `pool -> sink` edge is capacity-bound by the pool's free replica slots (plus
+/// one, if the pool already holds one of the volume's current replicas, since keeping it doesn't
+/// consume new capacity).
+pub(crate) struct RebalanceSolver;
+
+/// The pool ids currently holding one of the volume's replicas, used to bias placement toward
+/// keeping them there.
+pub(crate) type CurrentPools = HashSet<PoolId>;
+
+/// The computed replacement placement for a volume's replicas.
+#[derive(Debug, Clone)]
+pub(crate) struct RebalancePlan {
+    /// The pool chosen for each replica slot.
+    pub(crate) assignment: Vec<PoolId>,
+    /// How many of the chosen pools differ from `current`, ie how many replicas must move.
+    pub(crate) moves: usize,
+}
+
+impl RebalanceSolver {
+    /// Solve for the least-movement placement of `replica_count` replicas across `pools`. Returns
+    /// `None` if `replica_count` replicas can't all be placed (infeasible), in which case the
+    /// caller should fall back to the greedy `NodeFilters`/`SortBuilder` path.
+    pub(crate) fn solve(
+        pools: &[PoolItem],
+        replica_count: usize,
+        replica_size: u64,
+        current: &CurrentPools,
+    ) -> Option<RebalancePlan> {
+        let mut graph = FlowGraph::new(pools, replica_count, replica_size, current);
+
+        // First pass: Edmonds-Karp (BFS augmenting paths) to establish whether a feasible max
+        // flow of value `replica_count` even exists, ignoring cost.
+        if graph.edmonds_karp_max_flow() < replica_count {
+            return None;
+        }
+
+        // Second pass: cancel negative-cost cycles in the residual graph (Bellman-Ford) until
+        // none remain, turning the feasible max flow into a minimum-cost one.
+        graph.cancel_negative_cycles();
+
+        let assignment = graph.extract_assignment();
+        let moves = assignment.iter().filter(|pool| !current.contains(pool)).count();
+        Some(RebalancePlan { assignment, moves })
+    }
+}
+
+/// A directed edge in the residual graph of the flow network.
+#[derive(Debug, Clone, Copy)]
+struct Edge {
+    to: usize,
+    cap: i64,
+    cost: i64,
+}
+
+/// Flow network built up from replica slots, nodes and pools.
+struct FlowGraph {
+    /// Adjacency list of edges; edge `i` and its reverse residual edge are stored as `i^1`.
+    edges: Vec<Edge>,
+    adj: Vec<Vec<usize>>,
+    source: usize,
+    sink: usize,
+    // Node index ranges, used to classify an edge's endpoint without guessing from residual
+    // capacities: `[slot_base, node_in_base)` are replica slots, `[node_in_base, node_out_base)`
+    // and `[node_out_base, pool_base)` are the in/out halves of the per-node degree constraint,
+    // and `[pool_base, sink)` are pools.
+    slot_base: usize,
+    node_out_base: usize,
+    pool_base: usize,
+    n_slots: usize,
+    pool_ids: Vec<PoolId>,
+}
+
+impl FlowGraph {
+    fn new(
+        pools: &[PoolItem],
+        replica_count: usize,
+        replica_size: u64,
+        current: &CurrentPools,
+    ) -> Self {
+        let nodes = pools
+            .iter()
+            .map(|item| item.pool.node.clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        let source = 0;
+        let slot_base = 1;
+        let node_in_base = slot_base + replica_count;
+        let node_out_base = node_in_base + nodes.len();
+        let pool_base = node_out_base + nodes.len();
+        let sink = pool_base + pools.len();
+
+        let mut graph = FlowGraph {
+            edges: Vec::new(),
+            adj: vec![Vec::new(); sink + 1],
+            source,
+            sink,
+            slot_base,
+            node_out_base,
+            pool_base,
+            n_slots: replica_count,
+            pool_ids: pools.iter().map(|item| item.pool.id.clone()).collect(),
+        };
+
+        for slot in 0..replica_count {
+            graph.add_edge(source, slot_base + slot, 1, 0);
+        }
+        for (n, node) in nodes.iter().enumerate() {
+            // The degree-constraint split: whatever pools node `n` offers, at most one unit can
+            // flow through it in total, so it ends up hosting at most one replica.
+            graph.add_edge(node_in_base + n, node_out_base + n, 1, 0);
+            for slot in 0..replica_count {
+                graph.add_edge(slot_base + slot, node_in_base + n, 1, 0);
+            }
+        }
+        let node_index = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.clone(), i))
+            .collect::<std::collections::HashMap<_, _>>();
+        for (p, pool) in pools.iter().enumerate() {
+            let Some(&n) = node_index.get(&pool.pool.node) else {
+                continue;
+            };
+            let kept = current.contains(&pool.pool.id);
+            let cost = if kept { 0 } else { 1 };
+            graph.add_edge(node_out_base + n, pool_base + p, 1, cost);
+
+            // A pool already holding one of the volume's replicas doesn't need extra free space
+            // to keep hosting it, so its sink capacity is its free slots plus the one it retains.
+            let free_slots = (pool.free_space() / replica_size.max(1)).min(replica_count as u64);
+            let cap = free_slots as i64 + if kept { 1 } else { 0 };
+            graph.add_edge(pool_base + p, sink, cap, 0);
+        }
+
+        graph
+    }
+
+    fn add_edge(&mut self, from: usize, to: usize, cap: i64, cost: i64) {
+        let forward = self.edges.len();
+        self.edges.push(Edge { to, cap, cost });
+        self.adj[from].push(forward);
+
+        let backward = self.edges.len();
+        self.edges.push(Edge {
+            to: from,
+            cap: 0,
+            cost: -cost,
+        });
+        self.adj[to].push(backward);
+    }
+
+    /// Edmonds-Karp: repeatedly find a shortest (fewest-edges) augmenting path by BFS and push
+    /// one unit of flow along it (every augmenting path starts with a unit-capacity `source ->
+    /// slot` edge, so "shortest" and "any" augmenting path push the same amount). Returns the
+    /// resulting max flow value.
+    fn edmonds_karp_max_flow(&mut self) -> usize {
+        let mut flow = 0;
+        while let Some(path) = self.bfs_augmenting_path() {
+            for &e in &path {
+                self.edges[e].cap -= 1;
+                self.edges[e ^ 1].cap += 1;
+            }
+            flow += 1;
+        }
+        flow
+    }
+
+    fn bfs_augmenting_path(&self) -> Option<Vec<usize>> {
+        let n = self.adj.len();
+        let mut prev_edge: Vec<Option<usize>> = vec![None; n];
+        let mut visited = vec![false; n];
+        visited[self.source] = true;
+
+        let mut queue = VecDeque::new();
+        queue.push_back(self.source);
+        while let Some(u) = queue.pop_front() {
+            if u == self.sink {
+                break;
+            }
+            for &e in &self.adj[u] {
+                let edge = self.edges[e];
+                if edge.cap <= 0 || visited[edge.to] {
+                    continue;
+                }
+                visited[edge.to] = true;
+                prev_edge[edge.to] = Some(e);
+                queue.push_back(edge.to);
+            }
+        }
+
+        if !visited[self.sink] {
+            return None;
+        }
+        let mut path = Vec::new();
+        let mut v = self.sink;
+        while v != self.source {
+            let e = prev_edge[v].expect("reachable node has an incoming edge");
+            path.push(e);
+            v = self.edges[e ^ 1].to;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Bellman-Ford over the full residual graph (source included, though it carries no cost),
+    /// cancelling the first detected negative-cost cycle by pushing one unit of flow around it.
+    /// Repeated until a full pass finds no further relaxation, ie no negative cycle remains.
+    fn cancel_negative_cycles(&mut self) {
+        loop {
+            let n = self.adj.len();
+            let mut dist = vec![0_i64; n];
+            let mut prev_edge: Vec<Option<usize>> = vec![None; n];
+            let mut cycle_node = None;
+
+            for i in 0..n {
+                cycle_node = None;
+                for u in 0..n {
+                    for &e in &self.adj[u] {
+                        let edge = self.edges[e];
+                        if edge.cap <= 0 {
+                            continue;
+                        }
+                        if dist[u] + edge.cost < dist[edge.to] {
+                            dist[edge.to] = dist[u] + edge.cost;
+                            prev_edge[edge.to] = Some(e);
+                            if i == n - 1 {
+                                cycle_node = Some(edge.to);
+                            }
+                        }
+                    }
+                }
+                if cycle_node.is_some() {
+                    break;
+                }
+            }
+
+            let Some(mut v) = cycle_node else {
+                break;
+            };
+            // Walk back far enough to guarantee `v` is actually on the cycle, not just reachable
+            // from it.
+            for _ in 0..n {
+                v = self.edges[prev_edge[v].expect("relaxed node has an incoming edge") ^ 1].to;
+            }
+
+            let cycle_start = v;
+            let mut cycle = Vec::new();
+            loop {
+                let e = prev_edge[v].expect("cycle node has an incoming edge");
+                cycle.push(e);
+                v = self.edges[e ^ 1].to;
+                if v == cycle_start && !cycle.is_empty() {
+                    break;
+                }
+            }
+            for &e in &cycle {
+                self.edges[e].cap -= 1;
+                self.edges[e ^ 1].cap += 1;
+            }
+        }
+    }
+
+    fn extract_assignment(&self) -> Vec<PoolId> {
+        let mut assignment = Vec::new();
+        for slot in 0..self.n_slots {
+            let slot_node = self.slot_base + slot;
+            let Some(node_in) = self.saturated_target(slot_node, self.slot_base + self.n_slots, self.node_out_base)
+            else {
+                continue;
+            };
+            let Some(node_out) = self.saturated_target(node_in, self.node_out_base, self.pool_base)
+            else {
+                continue;
+            };
+            let Some(pool_node) = self.saturated_target(node_out, self.pool_base, self.sink)
+            else {
+                continue;
+            };
+            assignment.push(self.pool_ids[pool_node - self.pool_base].clone());
+        }
+        assignment
+    }
+
+    /// Find the forward edge out of `node` whose target falls in `[lo, hi)` and which carried
+    /// flow (its unit capacity has been consumed down to zero).
+    fn saturated_target(&self, node: usize, lo: usize, hi: usize) -> Option<usize> {
+        self.adj[node].iter().copied().find_map(|e| {
+            let edge = self.edges[e];
+            (edge.cap == 0 && edge.to >= lo && edge.to < hi).then_some(edge.to)
+        })
+    }
+}
@@ -0,0 +1,129 @@
+//! Power-of-two-choices replica placement, weighted by a decaying estimate of recent pool load.
+//!
+//! `SimplePolicy`/`ThickPolicy` narrow candidates down but otherwise leave ordering essentially
+//! deterministic, which packs new replicas onto whichever pool sorts first and leaves hot pools
+//! hot. [`BalancedPolicy`] instead samples two distinct surviving candidates at random and keeps
+//! the cheaper one, where cost trades off [`PoolLoadTracker`]'s load estimate against how much
+//! free space a pool has left. This statistically spreads load across pools in O(1) per
+//! decision, without needing full knowledge of every pool's current load like a sort would.
+
+use crate::controller::scheduling::{
+    resources::PoolItem, volume::AddVolumeReplica, ResourceFilter, ResourcePolicy,
+};
+use rand::Rng;
+use std::{collections::HashMap, time::Instant};
+use stor_port::types::v0::transport::PoolId;
+
+/// Time constant controlling how quickly a pool's load estimate forgets old samples: after
+/// `tau_ms` elapses with no new sample, a pool's previous load contributes only `1/e` as much.
+const DEFAULT_TAU_MS: f64 = 30_000.0;
+
+/// Decaying estimate of each pool's recent load (eg outstanding replica count, or recent
+/// provisioning latency), fed by whichever component observes placements/provisioning completing
+/// and consumed by [`BalancedPolicy`] to steer new replicas away from pools that are already busy.
+#[derive(Debug)]
+pub(crate) struct PoolLoadTracker {
+    tau_ms: f64,
+    load: HashMap<PoolId, (f64, Instant)>,
+}
+
+impl PoolLoadTracker {
+    /// Create a new `Self` with the default decay time constant.
+    pub(crate) fn new() -> Self {
+        Self {
+            tau_ms: DEFAULT_TAU_MS,
+            load: HashMap::new(),
+        }
+    }
+
+    /// Fold a new load `sample` into `pool`'s EWMA: `ewma = ewma * w + sample * (1 - w)` where
+    /// `w = exp(-elapsed_ms / tau)`, so a sample taken right after the last one barely moves the
+    /// estimate, while one taken long after dominates it. A pool's very first sample becomes its
+    /// initial estimate outright, rather than being diluted against an arbitrary starting value.
+    pub(crate) fn observe(&mut self, pool: &PoolId, sample: f64) {
+        let now = Instant::now();
+        match self.load.get_mut(pool) {
+            Some((ewma, last)) => {
+                let elapsed_ms = now.duration_since(*last).as_secs_f64() * 1000.0;
+                let w = (-elapsed_ms / self.tau_ms).exp();
+                *ewma = *ewma * w + sample * (1.0 - w);
+                *last = now;
+            }
+            None => {
+                self.load.insert(pool.clone(), (sample, now));
+            }
+        }
+    }
+
+    /// A pool's current load estimate; a pool that has never been observed is treated as
+    /// unloaded (`0.0`) so it gets an equal chance of being probed.
+    pub(crate) fn load(&self, pool: &PoolId) -> f64 {
+        self.load.get(pool).map_or(0.0, |(ewma, _)| *ewma)
+    }
+}
+
+impl Default for PoolLoadTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Selects the target pool for a new replica via power-of-two-choices over the candidates
+/// surviving `DefaultBasePolicy::filter_pools`, rather than a deterministic sort.
+pub(crate) struct BalancedPolicy<'a> {
+    load: &'a PoolLoadTracker,
+}
+
+impl<'a> BalancedPolicy<'a> {
+    /// Create a new `Self`, scoring candidates against the given load estimates.
+    pub(crate) fn new(load: &'a PoolLoadTracker) -> Self {
+        Self { load }
+    }
+
+    /// Lower is better: a pool's decaying load divided by how much of it is still free, so among
+    /// equally loaded pools the one with more room wins, and a busier pool loses out to an idler
+    /// one with the same room. A pool with no load costs `0.0` however full it is; a pool with no
+    /// free space left costs infinity.
+    fn cost(&self, item: &PoolItem) -> f64 {
+        let free_fraction = item.pool.free_space() as f64 / item.pool.capacity as f64;
+        if free_fraction <= 0.0 {
+            return f64::INFINITY;
+        }
+        self.load.load(&item.pool.id) / free_fraction
+    }
+
+    /// Pick one candidate by power-of-two-choices: sample two distinct candidates uniformly at
+    /// random and keep the cheaper one. With a single survivor there is nothing to choose
+    /// between, so it is returned outright.
+    fn pick<'b>(&self, candidates: &'b [PoolItem]) -> Option<&'b PoolItem> {
+        match candidates.len() {
+            0 => None,
+            1 => candidates.first(),
+            len => {
+                let mut rng = rand::thread_rng();
+                let i = rng.gen_range(0..len);
+                let mut j = rng.gen_range(0..len - 1);
+                if j >= i {
+                    j += 1;
+                }
+                let (a, b) = (&candidates[i], &candidates[j]);
+                if self.cost(a) <= self.cost(b) {
+                    Some(a)
+                } else {
+                    Some(b)
+                }
+            }
+        }
+    }
+}
+
+impl ResourcePolicy<AddVolumeReplica> for BalancedPolicy<'_> {
+    fn apply(self, mut to: AddVolumeReplica) -> AddVolumeReplica {
+        let data = to.data();
+        let Some(winner) = self.pick(&data.list).map(|item| item.pool.id.clone()) else {
+            return to;
+        };
+        data.list.retain(|item| item.pool.id == winner);
+        to
+    }
+}
@@ -6,10 +6,12 @@ use crate::controller::scheduling::{
 use tracing::info;
 
 mod affinity_group;
+mod balanced;
 pub(crate) mod pool;
 mod simple;
 mod thick;
 
+pub(super) use balanced::{BalancedPolicy, PoolLoadTracker};
 pub(super) use simple::SimplePolicy;
 pub(super) use thick::ThickPolicy;
 
@@ -22,6 +24,7 @@ impl DefaultBasePolicy {
     fn filter_nodes(request: AddVolumeReplica) -> AddVolumeReplica {
         request
             .filter(NodeFilters::cordoned_for_pool)
+            .filter(NodeFilters::draining_for_pool)
             .filter(NodeFilters::online_for_pool)
             .filter(NodeFilters::allowed)
             .filter(NodeFilters::unused)
@@ -32,6 +35,9 @@ impl DefaultBasePolicy {
             .filter(pool::PoolBaseFilters::capacity)
             .filter(pool::PoolBaseFilters::min_free_space)
             .filter(pool::PoolBaseFilters::topology)
+            .filter_iter(pool::PoolBaseFilters::spread_topology)
+            .filter_iter(pool::PoolBaseFilters::domain_spread)
+            .filter_iter(pool::PoolBaseFilters::flow_rank)
     }
     fn filter_snapshot(request: SnapshotVolumeReplica) -> SnapshotVolumeReplica {
         Self::filter_snapshot_pools(Self::filter_snapshot_nodes(request))
@@ -1,7 +1,21 @@
-use crate::controller::scheduling::{resources::PoolItem, volume::GetSuitablePoolsContext};
-use std::collections::HashMap;
-use stor_port::types::v0::transport::{PoolStatus, PoolTopology};
-use tracing::info;
+use crate::controller::scheduling::{
+    placement::ReplicaPlacementEngine, resources::PoolItem, volume::AddVolumeReplica,
+    volume::GetSuitablePoolsContext, ResourceFilter,
+};
+use std::collections::{HashMap, HashSet};
+use stor_port::types::v0::transport::{
+    NodeTopology, PoolState, PoolStatus, PoolTopology, SpreadMode,
+};
+
+/// Failure-domain label checked first when resolving a pool's spread domain: finest-grained and
+/// cheapest to keep distinct, so it takes priority over `RACK_LABEL`/`ZONE_LABEL`.
+const NODE_LABEL: &str = "kubernetes.io/hostname";
+/// Failure-domain label checked if `NODE_LABEL` isn't present; one level coarser than node.
+const RACK_LABEL: &str = "topology.kubernetes.io/rack";
+/// Failure-domain label checked last, same label the min-cost-max-flow placement engine uses for
+/// its own hard-distinct domain constraint; see `placement::ZONE_LABEL`.
+const ZONE_LABEL: &str = "topology.kubernetes.io/zone";
+
 /// Filter pools used for replica creation.
 pub(crate) struct PoolBaseFilters {}
 impl PoolBaseFilters {
@@ -9,6 +23,12 @@ impl PoolBaseFilters {
     fn free_space_watermark() -> u64 {
         16 * 1024 * 1024
     }
+    /// Cap on replicas-per-failure-domain enforced by `Self::domain_spread`; `None` disables the
+    /// check. Currently a fixed cluster-wide default, same as `Self::free_space_watermark` - a
+    /// natural extension point for a future storage class parameter.
+    fn max_replicas_per_domain(_request: &GetSuitablePoolsContext) -> Option<usize> {
+        Some(2)
+    }
     /// Should only attempt to use pools with capacity bigger than the requested replica size.
     pub(crate) fn capacity(request: &GetSuitablePoolsContext, item: &PoolItem) -> bool {
         item.pool.capacity > request.size
@@ -52,8 +72,8 @@ impl PoolBaseFilters {
     }
     /// Should only attempt to use pools having specific creation label if topology has it.
     pub(crate) fn topology(request: &GetSuitablePoolsContext, item: &PoolItem) -> bool {
-        let volume_pool_topology_labels: HashMap<String, String>;
-        info!("Aashvi {:?}", request.topology.clone());
+        let inclusion: HashMap<String, String>;
+        let exclusion: HashMap<String, String>;
         match request.topology.clone() {
             None => return true,
             Some(topology) => match topology.pool {
@@ -62,31 +82,229 @@ impl PoolBaseFilters {
                     PoolTopology::Labelled(labelled_topology) => {
                         // The labels in Volume Pool Topology should match the pool labels if
                         // present, otherwise selection of any pool is allowed.
-                        if !labelled_topology.inclusion.is_empty() {
-                            info!("ashish {:?}", labelled_topology.inclusion);
-                            volume_pool_topology_labels = labelled_topology.inclusion
-                        } else {
+                        if labelled_topology.inclusion.is_empty()
+                            && labelled_topology.exclusion.is_empty()
+                        {
                             return true;
                         }
+                        inclusion = labelled_topology.inclusion;
+                        exclusion = labelled_topology.exclusion;
                     }
                 },
             },
         };
         // We will reach this part of code only if the volume has pool topology labels.
-        match request.registry().specs().pool(&item.pool.id) {
-            Ok(spec) => match spec.labels {
-                None => false,
-                Some(label) => volume_pool_topology_labels
-                    .iter()
-                    .all(|(vol_key, vol_val)| {
-                        // See `InclusiveLabel` doc comment.
-                        // todo: add exclusion
-                        label
-                            .get(vol_key)
-                            .is_some_and(|pool_value| vol_val.is_empty() || pool_value == vol_val)
-                    }),
-            },
-            Err(_) => false,
+        let labels = match request.registry().specs().pool(&item.pool.id) {
+            Ok(spec) => spec.labels,
+            Err(_) => return false,
+        };
+        Self::inclusive_labels(&inclusion, &labels) && Self::exclusive_labels(&exclusion, &labels)
+    }
+    /// Spread replicas of the volume across distinct failure domains when the volume's topology
+    /// requests `NodeTopology::Spread { key, mode }`, grouping the remaining candidates by the
+    /// value of the `key` label (falling back to the pool's node, for pools without it).
+    ///
+    /// At most `cap` candidates are kept per domain: with `RequireDistinct` `cap` is always `1`,
+    /// so a domain with fewer distinct values than replicas simply starves the creation of enough
+    /// candidates rather than silently doubling up a failure domain; with `BestEffort` `cap` is
+    /// `ceil(replica_count / domain_count)`, which still favours spreading but allows doubling up
+    /// once every domain already has at least one candidate.
+    pub(crate) fn spread_topology(mut request: AddVolumeReplica) -> AddVolumeReplica {
+        let Some(topology) = request.data().context().topology.clone() else {
+            return request;
+        };
+        let Some(NodeTopology::Spread { key, mode }) = topology.node else {
+            return request;
+        };
+        let replica_count = request.data().context().vol_spec().num_replicas.max(1) as usize;
+
+        let domain_of = |item: &PoolItem| -> String {
+            item.pool
+                .labels
+                .as_ref()
+                .and_then(|labels| labels.get(&key))
+                .cloned()
+                .unwrap_or_else(|| format!("node/{}", item.pool.node))
+        };
+
+        let data = request.data();
+        let domain_count = data
+            .list
+            .iter()
+            .map(&domain_of)
+            .collect::<std::collections::HashSet<_>>()
+            .len()
+            .max(1);
+        let cap = match mode {
+            SpreadMode::RequireDistinct => 1,
+            SpreadMode::BestEffort => replica_count.div_ceil(domain_count),
+        };
+
+        let mut seen_per_domain: HashMap<String, usize> = HashMap::new();
+        data.list.retain(|item| {
+            let count = seen_per_domain.entry(domain_of(item)).or_insert(0);
+            let keep = *count < cap;
+            if keep {
+                *count += 1;
+            }
+            keep
+        });
+
+        request
+    }
+    /// A pool's failure domain, for `Self::domain_spread`: the first of `NODE_LABEL`,
+    /// `RACK_LABEL`, `ZONE_LABEL` present on the pool, falling back to the pool's node (same
+    /// fallback `spread_topology` uses) when none of them are.
+    fn domain_of(item: &PoolItem) -> String {
+        [NODE_LABEL, RACK_LABEL, ZONE_LABEL]
+            .into_iter()
+            .find_map(|label| {
+                item.pool
+                    .labels
+                    .as_ref()
+                    .and_then(|labels| labels.get(label))
+                    .cloned()
+            })
+            .unwrap_or_else(|| format!("node/{}", item.pool.node))
+    }
+    /// Rejects candidates that would push their failure domain past
+    /// `Self::max_replicas_per_domain`, counting the domains of the volume's already-placed
+    /// replicas (derived from its other data nodes) against the same budget so a volume can't be
+    /// rebalanced into over-packing a domain it's already using.
+    ///
+    /// Unlike `Self::spread_topology` (a hard opt-in via the volume's own `NodeTopology::Spread`),
+    /// this is a standing safety net: it applies to every volume, using whichever domain label is
+    /// present, not just the one a volume's topology happens to name. If no candidate fits, the
+    /// list is emptied so placement fails with a clear diagnostic instead of silently over-packing
+    /// a domain.
+    pub(crate) fn domain_spread(mut request: AddVolumeReplica) -> AddVolumeReplica {
+        let Some(max_per_domain) = Self::max_replicas_per_domain(request.data().context()) else {
+            return request;
+        };
+
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        {
+            let ctx = request.data().context();
+            let registry = ctx.registry();
+            let used_nodes = registry.specs().volume_data_nodes(&ctx.uuid);
+            for node in &used_nodes {
+                let labels = registry.specs().node(node).ok().and_then(|spec| spec.labels);
+                let domain = [NODE_LABEL, RACK_LABEL, ZONE_LABEL]
+                    .into_iter()
+                    .find_map(|label| labels.as_ref().and_then(|l| l.get(label)).cloned())
+                    .unwrap_or_else(|| format!("node/{node}"));
+                *counts.entry(domain).or_insert(0) += 1;
+            }
         }
+
+        let uuid = request.data().context().uuid.clone();
+        let data = request.data();
+        let had_candidates = !data.list.is_empty();
+        data.list.retain(|item| {
+            let count = counts.entry(Self::domain_of(item)).or_insert(0);
+            let keep = *count < max_per_domain;
+            if keep {
+                *count += 1;
+            }
+            keep
+        });
+
+        if had_candidates && data.list.is_empty() {
+            tracing::warn!(
+                %uuid,
+                max_per_domain,
+                "no pool candidate satisfies the per-domain replica spread constraint; \
+                 failing placement rather than over-packing a single domain"
+            );
+        }
+
+        request
+    }
+    /// Reorder the remaining candidates by the min-cost-max-flow zone-spread solver in
+    /// [`ReplicaPlacementEngine::rank_pools`], instead of leaving `domain_spread`'s fixed
+    /// per-domain cap as the only signal candidate pools get scored on.
+    ///
+    /// Pools the solver reaches for one of the volume's still-unfilled replica slots are moved to
+    /// the front, in its preferred (emptiest-first, zone-spreading) order; everything else keeps
+    /// its existing relative order behind them, so a solver miss (eg it couldn't reach every pool)
+    /// degrades to the prior ordering rather than dropping candidates.
+    pub(crate) fn flow_rank(mut request: AddVolumeReplica) -> AddVolumeReplica {
+        let (replica_count, replica_size, used_zones) = {
+            let ctx = request.data().context();
+            let registry = ctx.registry();
+            let used_zones = registry
+                .specs()
+                .volume_data_nodes(&ctx.uuid)
+                .iter()
+                .filter_map(|node| registry.specs().node(node).ok())
+                .filter_map(|spec| spec.labels)
+                .filter_map(|labels| labels.get(ZONE_LABEL).cloned())
+                .collect::<HashSet<_>>();
+            (
+                ctx.vol_spec().num_replicas.max(1) as usize,
+                ctx.size,
+                used_zones,
+            )
+        };
+
+        let data = request.data();
+        let pools = data
+            .list
+            .iter()
+            .map(|item| PoolState {
+                node: item.pool.node.clone(),
+                id: item.pool.id.clone(),
+                status: item.pool.status.clone(),
+                capacity: item.pool.capacity,
+                used: item.pool.capacity.saturating_sub(item.pool.free_space()),
+                labels: item.pool.labels.clone(),
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+
+        let ranked = ReplicaPlacementEngine::rank_pools(&pools, replica_count, replica_size, &used_zones);
+        if !ranked.is_empty() {
+            let rank_of = ranked
+                .iter()
+                .enumerate()
+                .map(|(i, id)| (id.clone(), i))
+                .collect::<HashMap<_, _>>();
+            data.list.sort_by_key(|item| {
+                rank_of.get(&item.pool.id).copied().unwrap_or(ranked.len())
+            });
+        }
+
+        request
+    }
+    /// A pool passes inclusion when every requested key is present and its value either
+    /// matches the pool's value or the requested value is empty (any value accepted).
+    fn inclusive_labels(inclusion: &HashMap<String, String>, labels: &Option<HashMap<String, String>>) -> bool {
+        if inclusion.is_empty() {
+            return true;
+        }
+        let labels = match labels {
+            None => return false,
+            Some(labels) => labels,
+        };
+        inclusion.iter().all(|(key, value)| {
+            labels
+                .get(key)
+                .is_some_and(|pool_value| value.is_empty() || pool_value == value)
+        })
+    }
+    /// A pool passes exclusion when, for every excluded key, the pool either lacks the key or
+    /// its value differs from the excluded value. This is how volumes express anti-affinity
+    /// away from pools carrying a given label, eg for fault-domain spreading.
+    fn exclusive_labels(exclusion: &HashMap<String, String>, labels: &Option<HashMap<String, String>>) -> bool {
+        if exclusion.is_empty() {
+            return true;
+        }
+        let labels = match labels {
+            None => return true,
+            Some(labels) => labels,
+        };
+        exclusion.iter().all(|(key, value)| {
+            !labels.get(key).is_some_and(|pool_value| pool_value == value)
+        })
     }
 }
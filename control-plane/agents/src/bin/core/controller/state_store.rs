@@ -0,0 +1,217 @@
+//! Pluggable persistence for [`ResourceStates`](crate::controller::states::ResourceStates)
+//! checkpoints.
+//!
+//! A restarting core agent has no view of the cluster's resources until the first full node poll
+//! completes. [`StateStore`] lets [`ResourceStatesLocked`](crate::controller::states::ResourceStatesLocked)
+//! warm its cache from whatever was last persisted instead of starting blind, cutting that
+//! cold-start window. Persistence happens only at checkpoint boundaries (see
+//! `ResourceStates::checkpoint_due`), not on every mutation, since the short operation-log replay
+//! already covers the gap between checkpoints.
+
+use crate::controller::states::StateCheckpoint;
+use snafu::Snafu;
+use std::path::{Path, PathBuf};
+
+/// Which of [`StateCheckpoint`]'s four resource maps a persisted entry belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub(crate) enum ResourceKind {
+    Nexus,
+    Pool,
+    Replica,
+    Snapshot,
+}
+
+/// Error returned by a [`StateStore`] implementation.
+#[derive(Debug, Snafu)]
+#[snafu(visibility(pub(crate)))]
+pub(crate) enum StateStoreError {
+    #[snafu(display("Failed to open state store at '{}': {}", path.display(), source))]
+    Open {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[snafu(display("Failed to read entry '{}' from the state store: {}", key, source))]
+    Read { key: String, source: heed::Error },
+    #[snafu(display("Failed to write entry '{}' to the state store: {}", key, source))]
+    Write { key: String, source: heed::Error },
+    #[snafu(display("Failed to flush the state store: {}", source))]
+    Flush { source: heed::Error },
+    #[snafu(display("Failed to clear the state store: {}", source))]
+    Clear { source: heed::Error },
+}
+
+/// Persists [`StateCheckpoint`]s to a key-value backend, keyed by `(resource_kind, id)` so each
+/// resource can be loaded or replaced independently of its peers.
+pub(crate) trait StateStore: Send + Sync {
+    /// Load the most recently persisted checkpoint, or an empty one if nothing has been
+    /// persisted yet.
+    fn load(&self) -> Result<StateCheckpoint, StateStoreError>;
+    /// Persist a full checkpoint, one entry per `(resource_kind, id)`, replacing whatever was
+    /// previously stored for that key.
+    fn store(&self, checkpoint: &StateCheckpoint) -> Result<(), StateStoreError>;
+    /// Durably flush whatever `store` wrote.
+    fn flush(&self) -> Result<(), StateStoreError>;
+    /// Truncate the backing store, eg alongside `ResourceStates::clear_all`.
+    fn clear(&self) -> Result<(), StateStoreError>;
+}
+
+/// In-memory no-op [`StateStore`], used when persistence is disabled: `load` always returns an
+/// empty checkpoint and every write is silently discarded.
+#[derive(Debug, Default)]
+pub(crate) struct NullStateStore {}
+
+impl NullStateStore {
+    /// Create a new `Self`.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl StateStore for NullStateStore {
+    fn load(&self) -> Result<StateCheckpoint, StateStoreError> {
+        Ok(StateCheckpoint::default())
+    }
+    fn store(&self, _checkpoint: &StateCheckpoint) -> Result<(), StateStoreError> {
+        Ok(())
+    }
+    fn flush(&self) -> Result<(), StateStoreError> {
+        Ok(())
+    }
+    fn clear(&self) -> Result<(), StateStoreError> {
+        Ok(())
+    }
+}
+
+/// A single persisted resource, the value half of a `(resource_kind, id)` entry.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+enum StateEntry {
+    Nexus(stor_port::types::v0::store::nexus::NexusState),
+    Pool(stor_port::types::v0::store::pool::PoolState),
+    Replica(stor_port::types::v0::store::replica::ReplicaState),
+    Snapshot(stor_port::types::v0::store::snapshots::ReplicaSnapshotState),
+}
+
+/// Embedded LMDB-backed [`StateStore`].
+pub(crate) struct LmdbStateStore {
+    env: heed::Env,
+    db: heed::Database<heed::types::Str, heed::types::SerdeBincode<StateEntry>>,
+}
+
+impl LmdbStateStore {
+    /// Open (creating if necessary) an LMDB environment rooted at `path` to back the state cache.
+    pub(crate) fn open(path: &Path) -> Result<Self, StateStoreError> {
+        std::fs::create_dir_all(path).map_err(|source| StateStoreError::Open {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let env = unsafe {
+            heed::EnvOpenOptions::new()
+                .map_size(1024 * 1024 * 1024)
+                .open(path)
+        }
+        .map_err(|source| StateStoreError::Read {
+            key: path.display().to_string(),
+            source,
+        })?;
+        let mut txn = env.write_txn().map_err(|source| StateStoreError::Write {
+            key: "<init>".to_string(),
+            source,
+        })?;
+        let db = env
+            .create_database(&mut txn, Some("resource_states"))
+            .map_err(|source| StateStoreError::Write {
+                key: "<init>".to_string(),
+                source,
+            })?;
+        txn.commit().map_err(|source| StateStoreError::Write {
+            key: "<init>".to_string(),
+            source,
+        })?;
+        Ok(Self { env, db })
+    }
+
+    /// The key a resource of the given `kind` and `id` is stored under.
+    fn key(kind: ResourceKind, id: &str) -> String {
+        format!("{kind:?}/{id}")
+    }
+}
+
+impl StateStore for LmdbStateStore {
+    fn load(&self) -> Result<StateCheckpoint, StateStoreError> {
+        let txn = self.env.read_txn().map_err(|source| StateStoreError::Read {
+            key: "<txn>".to_string(),
+            source,
+        })?;
+        let mut checkpoint = StateCheckpoint::default();
+        for entry in self.db.iter(&txn).map_err(|source| StateStoreError::Read {
+            key: "<iter>".to_string(),
+            source,
+        })? {
+            let (key, value) = entry.map_err(|source| StateStoreError::Read {
+                key: "<iter>".to_string(),
+                source,
+            })?;
+            match value {
+                StateEntry::Nexus(state) => checkpoint.nexuses.push(state),
+                StateEntry::Pool(state) => checkpoint.pools.push(state),
+                StateEntry::Replica(state) => checkpoint.replicas.push(state),
+                StateEntry::Snapshot(state) => checkpoint.snapshots.push(state),
+            }
+            let _ = key;
+        }
+        Ok(checkpoint)
+    }
+
+    fn store(&self, checkpoint: &StateCheckpoint) -> Result<(), StateStoreError> {
+        let mut txn = self.env.write_txn().map_err(|source| StateStoreError::Write {
+            key: "<txn>".to_string(),
+            source,
+        })?;
+        self.db.clear(&mut txn).map_err(|source| StateStoreError::Write {
+            key: "<clear>".to_string(),
+            source,
+        })?;
+        for nexus in &checkpoint.nexuses {
+            let key = Self::key(ResourceKind::Nexus, &nexus.uuid.to_string());
+            self.db
+                .put(&mut txn, &key, &StateEntry::Nexus(nexus.clone()))
+                .map_err(|source| StateStoreError::Write { key, source })?;
+        }
+        for pool in &checkpoint.pools {
+            let key = Self::key(ResourceKind::Pool, &pool.id.to_string());
+            self.db
+                .put(&mut txn, &key, &StateEntry::Pool(pool.clone()))
+                .map_err(|source| StateStoreError::Write { key, source })?;
+        }
+        for replica in &checkpoint.replicas {
+            let key = Self::key(ResourceKind::Replica, &replica.uuid.to_string());
+            self.db
+                .put(&mut txn, &key, &StateEntry::Replica(replica.clone()))
+                .map_err(|source| StateStoreError::Write { key, source })?;
+        }
+        for snapshot in &checkpoint.snapshots {
+            let key = Self::key(ResourceKind::Snapshot, &snapshot.uuid.to_string());
+            self.db
+                .put(&mut txn, &key, &StateEntry::Snapshot(snapshot.clone()))
+                .map_err(|source| StateStoreError::Write { key, source })?;
+        }
+        txn.commit().map_err(|source| StateStoreError::Write {
+            key: "<commit>".to_string(),
+            source,
+        })
+    }
+
+    fn flush(&self) -> Result<(), StateStoreError> {
+        self.env
+            .force_sync()
+            .map_err(|source| StateStoreError::Flush { source })
+    }
+
+    fn clear(&self) -> Result<(), StateStoreError> {
+        let mut txn = self.env.write_txn().map_err(|source| StateStoreError::Clear { source })?;
+        self.db
+            .clear(&mut txn)
+            .map_err(|source| StateStoreError::Clear { source })?;
+        txn.commit().map_err(|source| StateStoreError::Clear { source })
+    }
+}
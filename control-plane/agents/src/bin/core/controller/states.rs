@@ -1,4 +1,7 @@
-use crate::controller::resources::{resource_map::ResourceMap, ResourceMutex};
+use crate::controller::{
+    resources::{resource_map::ResourceMap, ResourceMutex},
+    state_store::{NullStateStore, StateStore, StateStoreError},
+};
 use stor_port::types::v0::{
     store::{nexus::NexusState, pool::PoolState, replica::ReplicaState},
     transport::{self, Nexus, NexusId, PoolId, Replica, ReplicaId},
@@ -6,40 +9,203 @@ use stor_port::types::v0::{
 
 use indexmap::map::Values;
 use parking_lot::RwLock;
-use std::{ops::Deref, sync::Arc};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, ops::Deref, sync::Arc};
 use stor_port::types::v0::{
     store::snapshots::ReplicaSnapshotState,
     transport::{ReplicaSnapshot, SnapshotId},
 };
+use tokio::sync::broadcast;
+
+/// Number of applied operations kept between automatic checkpoints of the full resource state.
+/// A lower value shortens the replay tail after a restart at the cost of more frequent
+/// checkpoint serialisation; a higher value does the opposite.
+pub(crate) const KEEP_STATE_EVERY: usize = 64;
+
+/// Capacity of each per-resource-kind change subscription channel. A subscriber which falls more
+/// than this many deltas behind is considered lagged (see [`ResourceSubscription::recv`]).
+const SUBSCRIPTION_CAPACITY: usize = 512;
 
 /// Locked Resource States.
-#[derive(Clone, Default, Debug)]
-pub(crate) struct ResourceStatesLocked(Arc<RwLock<ResourceStates>>);
+///
+/// Besides the in-memory maps, this also owns the pluggable [`StateStore`] that checkpoints are
+/// persisted to (see [`Self::checkpoint_if_due`]), so a restarting agent can warm its cache via
+/// [`Self::warm_from_store`] before the first full node poll completes.
+#[derive(Clone)]
+pub(crate) struct ResourceStatesLocked {
+    states: Arc<RwLock<ResourceStates>>,
+    store: Arc<dyn StateStore>,
+}
+
+impl std::fmt::Debug for ResourceStatesLocked {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResourceStatesLocked")
+            .field("states", &self.states)
+            .finish()
+    }
+}
+
+impl Default for ResourceStatesLocked {
+    /// Returns a new `Self` backed by a [`NullStateStore`], ie with persistence disabled.
+    fn default() -> Self {
+        Self::new_with_store(Arc::new(NullStateStore::new()))
+    }
+}
 
 impl ResourceStatesLocked {
-    /// Return a new empty `Self`.
+    /// Return a new empty `Self`, with persistence disabled.
     pub(crate) fn new() -> Self {
         Default::default()
     }
+
+    /// Return a new empty `Self` that persists checkpoints via `store`.
+    pub(crate) fn new_with_store(store: Arc<dyn StateStore>) -> Self {
+        Self {
+            states: Default::default(),
+            store,
+        }
+    }
+
+    /// Warm the cache from the backing store's last persisted checkpoint, replacing whatever the
+    /// in-memory maps held. If nothing has been persisted yet (eg a brand new cluster, or
+    /// persistence disabled) the maps are left empty.
+    pub(crate) fn warm_from_store(&self) -> Result<(), StateStoreError> {
+        let checkpoint = self.store.load()?;
+        self.write().restore(checkpoint, Vec::new());
+        Ok(())
+    }
+
+    /// Take a checkpoint if one is due (see [`ResourceStates::checkpoint_due`]) and persist it to
+    /// the backing store. Persistence only happens at these checkpoint boundaries, not on every
+    /// mutation, since the short operation-log tail already covers the gap between them.
+    pub(crate) fn checkpoint_if_due(&self) -> Result<(), StateStoreError> {
+        let checkpoint = {
+            let mut states = self.write();
+            if !states.checkpoint_due() {
+                return Ok(());
+            }
+            states.checkpoint()
+        };
+        self.store.store(&checkpoint)?;
+        self.store.flush()
+    }
+
+    /// Clear all in-memory state and truncate the backing store along with it.
+    pub(crate) fn clear_all(&self) -> Result<(), StateStoreError> {
+        self.write().clear_all();
+        self.store.clear()
+    }
+
+    /// Subscribe to nexus change events. See [`ResourceStates::subscribe_nexuses`].
+    pub(crate) fn subscribe_nexuses(&self) -> ResourceSubscription<NexusState, NexusId> {
+        self.read().subscribe_nexuses()
+    }
+
+    /// Subscribe to pool change events. See [`ResourceStates::subscribe_pools`].
+    pub(crate) fn subscribe_pools(&self) -> ResourceSubscription<PoolState, PoolId> {
+        self.read().subscribe_pools()
+    }
+
+    /// Subscribe to replica change events. See [`ResourceStates::subscribe_replicas`].
+    pub(crate) fn subscribe_replicas(&self) -> ResourceSubscription<ReplicaState, ReplicaId> {
+        self.read().subscribe_replicas()
+    }
+
+    /// Subscribe to snapshot change events. See [`ResourceStates::subscribe_snapshots`].
+    pub(crate) fn subscribe_snapshots(
+        &self,
+    ) -> ResourceSubscription<ReplicaSnapshotState, SnapshotId> {
+        self.read().subscribe_snapshots()
+    }
 }
 
 impl Deref for ResourceStatesLocked {
     type Target = Arc<RwLock<ResourceStates>>;
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.states
     }
 }
 
 /// Resource States.
-#[derive(Default, Debug)]
+///
+/// Besides the live resource maps, this also keeps a log of the operations applied since the
+/// last checkpoint (see [`ResourceStates::checkpoint`]), so that a restart can restore the
+/// newest checkpoint and replay just that tail instead of waiting for a full re-poll of every
+/// node. Every `update_*` mutation additionally publishes a [`ResourceDelta`] on a per-resource-kind
+/// broadcast channel, letting subscribers (see [`ResourceStates::subscribe_pools`] and peers)
+/// react to exactly what changed instead of diffing full clones themselves.
+#[derive(Debug)]
 pub(crate) struct ResourceStates {
     nexuses: ResourceMap<NexusId, NexusState>,
     pools: ResourceMap<PoolId, PoolState>,
     replicas: ResourceMap<ReplicaId, ReplicaState>,
     snapshots: ResourceMap<SnapshotId, ReplicaSnapshotState>,
+    /// Operations applied since the last checkpoint was taken.
+    ops_since_checkpoint: Vec<StateOp>,
+    nexus_tx: broadcast::Sender<ResourceDelta<NexusState, NexusId>>,
+    pool_tx: broadcast::Sender<ResourceDelta<PoolState, PoolId>>,
+    replica_tx: broadcast::Sender<ResourceDelta<ReplicaState, ReplicaId>>,
+    snapshot_tx: broadcast::Sender<ResourceDelta<ReplicaSnapshotState, SnapshotId>>,
+}
+
+impl Default for ResourceStates {
+    fn default() -> Self {
+        Self {
+            nexuses: Default::default(),
+            pools: Default::default(),
+            replicas: Default::default(),
+            snapshots: Default::default(),
+            ops_since_checkpoint: Default::default(),
+            nexus_tx: broadcast::channel(SUBSCRIPTION_CAPACITY).0,
+            pool_tx: broadcast::channel(SUBSCRIPTION_CAPACITY).0,
+            replica_tx: broadcast::channel(SUBSCRIPTION_CAPACITY).0,
+            snapshot_tx: broadcast::channel(SUBSCRIPTION_CAPACITY).0,
+        }
+    }
+}
+
+/// A single change to a resource's state, published as mutations are applied to
+/// [`ResourceStates`].
+#[derive(Debug, Clone)]
+pub(crate) enum ResourceDelta<S, I> {
+    /// A resource was added.
+    Added(S),
+    /// A resource changed from `old` to `new`.
+    Updated { old: S, new: S },
+    /// A resource was removed.
+    Removed(I),
+}
+
+/// An event delivered by a [`ResourceSubscription`]: either the next delta, or a signal that the
+/// subscriber fell behind and must resynchronise from a fresh snapshot (eg via
+/// `pool_states_cloned()`) before trusting further deltas.
+#[derive(Debug, Clone)]
+pub(crate) enum SubscriptionEvent<S, I> {
+    /// The next change in order.
+    Delta(ResourceDelta<S, I>),
+    /// One or more deltas were missed; re-fetch a full snapshot before continuing.
+    Resync,
+}
+
+/// A subscription to a resource kind's change events, returned by `subscribe_pools()` and peers.
+pub(crate) struct ResourceSubscription<S, I> {
+    rx: broadcast::Receiver<ResourceDelta<S, I>>,
+}
+
+impl<S: Clone, I: Clone> ResourceSubscription<S, I> {
+    /// Await the next event. A detected lag is surfaced as [`SubscriptionEvent::Resync`] rather
+    /// than silently skipping ahead, so the caller knows it must re-fetch a full snapshot.
+    pub(crate) async fn recv(&mut self) -> SubscriptionEvent<S, I> {
+        match self.rx.recv().await {
+            Ok(delta) => SubscriptionEvent::Delta(delta),
+            Err(broadcast::error::RecvError::Lagged(_)) => SubscriptionEvent::Resync,
+            Err(broadcast::error::RecvError::Closed) => SubscriptionEvent::Resync,
+        }
+    }
 }
 
 /// Add/Update or remove resource from the registry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) enum Either<R, I> {
     /// Insert the resource `R` in the registry.
     Insert(R),
@@ -47,6 +213,33 @@ pub(crate) enum Either<R, I> {
     Remove(I),
 }
 
+/// A single resource mutation, as logged by the per-resource `update_*` methods. Replaying these
+/// in order after restoring a [`StateCheckpoint`] brings `ResourceStates` back to where it was
+/// before a restart.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) enum StateOp {
+    /// See [`ResourceStates::update_nexus`].
+    Nexus(Either<Nexus, NexusId>),
+    /// See [`ResourceStates::update_pool`].
+    Pool(Either<transport::PoolState, PoolId>),
+    /// See [`ResourceStates::update_replica`].
+    Replica(Either<Replica, ReplicaId>),
+    /// See [`ResourceStates::update_snapshot`].
+    Snapshot(Either<ReplicaSnapshot, SnapshotId>),
+}
+
+/// A full point-in-time snapshot of all resource maps, serialised periodically so that a restart
+/// can load the newest one and only replay the (short) tail of operations logged after it. Also
+/// the unit persisted by a [`crate::controller::state_store::StateStore`], one entry per
+/// `(resource_kind, id)`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct StateCheckpoint {
+    pub(crate) nexuses: Vec<NexusState>,
+    pub(crate) pools: Vec<PoolState>,
+    pub(crate) replicas: Vec<ReplicaState>,
+    pub(crate) snapshots: Vec<ReplicaSnapshotState>,
+}
+
 impl ResourceStates {
     /// Update the various resource states.
     pub(crate) fn update(
@@ -64,18 +257,37 @@ impl ResourceStates {
 
     /// Update nexus states.
     pub(crate) fn update_nexuses(&mut self, nexuses: Vec<Nexus>) {
+        let new: Vec<NexusState> = nexuses.into_iter().map(Into::into).collect();
+        let deltas = Self::diff(self.nexus_states_cloned(), new.clone(), |state| {
+            state.uuid.clone()
+        });
         self.nexuses.clear();
-        self.nexuses.populate(nexuses);
+        self.nexuses.populate(new);
+        for delta in deltas {
+            let _ = self.nexus_tx.send(delta);
+        }
     }
 
     /// Update nexus state.
     pub(crate) fn update_nexus(&mut self, state: Either<Nexus, NexusId>) {
+        self.log_op(StateOp::Nexus(state.clone()));
         match state {
             Either::Insert(nexus) => {
-                self.nexuses.insert(nexus.into());
+                let new: NexusState = nexus.into();
+                let old = self.nexuses.get(&new.uuid).map(|state| state.lock().clone());
+                self.nexuses.insert(new.clone());
+                let delta = match old {
+                    Some(old) => ResourceDelta::Updated { old, new },
+                    None => ResourceDelta::Added(new),
+                };
+                let _ = self.nexus_tx.send(delta);
             }
             Either::Remove(nexus) => {
-                self.nexuses.remove(&nexus);
+                // A replayed Remove on an id that's no longer (or never was) present is a no-op.
+                if self.nexuses.get(&nexus).is_some() {
+                    self.nexuses.remove(&nexus);
+                    let _ = self.nexus_tx.send(ResourceDelta::Removed(nexus));
+                }
             }
         }
     }
@@ -97,18 +309,34 @@ impl ResourceStates {
 
     /// Update pool states.
     pub(crate) fn update_pools(&mut self, pools: Vec<transport::PoolState>) {
+        let new: Vec<PoolState> = pools.into_iter().map(Into::into).collect();
+        let deltas = Self::diff(self.pool_states_cloned(), new.clone(), |state| state.id.clone());
         self.pools.clear();
-        self.pools.populate(pools);
+        self.pools.populate(new);
+        for delta in deltas {
+            let _ = self.pool_tx.send(delta);
+        }
     }
 
     /// Update pool state.
     pub(crate) fn update_pool(&mut self, state: Either<transport::PoolState, PoolId>) {
+        self.log_op(StateOp::Pool(state.clone()));
         match state {
             Either::Insert(pool) => {
-                self.pools.insert(pool.into());
+                let new: PoolState = pool.into();
+                let old = self.pools.get(&new.id).map(|state| state.lock().clone());
+                self.pools.insert(new.clone());
+                let delta = match old {
+                    Some(old) => ResourceDelta::Updated { old, new },
+                    None => ResourceDelta::Added(new),
+                };
+                let _ = self.pool_tx.send(delta);
             }
             Either::Remove(pool) => {
-                self.pools.remove(&pool);
+                if self.pools.get(&pool).is_some() {
+                    self.pools.remove(&pool);
+                    let _ = self.pool_tx.send(ResourceDelta::Removed(pool));
+                }
             }
         }
     }
@@ -131,40 +359,92 @@ impl ResourceStates {
 
     /// Update replica states.
     pub(crate) fn update_replicas(&mut self, replicas: Vec<Replica>) {
+        let new: Vec<ReplicaState> = replicas.into_iter().map(Into::into).collect();
+        let deltas = Self::diff(self.replica_states_cloned(), new.clone(), |state| {
+            state.uuid.clone()
+        });
         self.replicas.clear();
-        self.replicas.populate(replicas);
+        self.replicas.populate(new);
+        for delta in deltas {
+            let _ = self.replica_tx.send(delta);
+        }
     }
 
     /// Update replica state.
     pub(crate) fn update_replica(&mut self, state: Either<Replica, ReplicaId>) {
+        self.log_op(StateOp::Replica(state.clone()));
         match state {
             Either::Insert(replica) => {
-                self.replicas.insert(replica.into());
+                let new: ReplicaState = replica.into();
+                let old = self
+                    .replicas
+                    .get(&new.uuid)
+                    .map(|state| state.lock().clone());
+                self.replicas.insert(new.clone());
+                let delta = match old {
+                    Some(old) => ResourceDelta::Updated { old, new },
+                    None => ResourceDelta::Added(new),
+                };
+                let _ = self.replica_tx.send(delta);
             }
             Either::Remove(replica) => {
-                self.replicas.remove(&replica);
+                if self.replicas.get(&replica).is_some() {
+                    self.replicas.remove(&replica);
+                    let _ = self.replica_tx.send(ResourceDelta::Removed(replica));
+                }
             }
         }
     }
 
     /// Update snapshot states.
     pub(crate) fn update_snapshots(&mut self, snapshots: Vec<ReplicaSnapshot>) {
+        let new: Vec<ReplicaSnapshotState> = snapshots.into_iter().map(Into::into).collect();
+        let deltas = Self::diff(self.snapshot_states_cloned(), new.clone(), |state| {
+            state.uuid.clone()
+        });
         self.snapshots.clear();
-        self.snapshots.populate(snapshots);
+        self.snapshots.populate(new);
+        for delta in deltas {
+            let _ = self.snapshot_tx.send(delta);
+        }
     }
 
     /// Update snapshot state.
     pub(crate) fn update_snapshot(&mut self, state: Either<ReplicaSnapshot, SnapshotId>) {
+        self.log_op(StateOp::Snapshot(state.clone()));
         match state {
             Either::Insert(snapshot) => {
-                self.snapshots.insert(snapshot.into());
+                let new: ReplicaSnapshotState = snapshot.into();
+                let old = self
+                    .snapshots
+                    .get(&new.uuid)
+                    .map(|state| state.lock().clone());
+                self.snapshots.insert(new.clone());
+                let delta = match old {
+                    Some(old) => ResourceDelta::Updated { old, new },
+                    None => ResourceDelta::Added(new),
+                };
+                let _ = self.snapshot_tx.send(delta);
             }
             Either::Remove(snapshot) => {
-                self.snapshots.remove(&snapshot);
+                if self.snapshots.get(&snapshot).is_some() {
+                    self.snapshots.remove(&snapshot);
+                    let _ = self.snapshot_tx.send(ResourceDelta::Removed(snapshot));
+                }
             }
         }
     }
 
+    /// Returns a vector of cloned snapshot states.
+    pub(crate) fn snapshot_states_cloned(&self) -> Vec<ReplicaSnapshotState> {
+        Self::cloned_inner_states(self.snapshots.values())
+    }
+
+    /// Returns an iterator of snapshot states.
+    pub(crate) fn snapshot_states(&self) -> Values<SnapshotId, ResourceMutex<ReplicaSnapshotState>> {
+        self.snapshots.values()
+    }
+
     /// Returns a vector of cloned replica states.
     pub(crate) fn replica_states_cloned(&self) -> Vec<ReplicaState> {
         Self::cloned_inner_states(self.replicas.values())
@@ -194,6 +474,113 @@ impl ResourceStates {
         self.pools.clear();
         self.replicas.clear();
         self.snapshots.clear();
+        self.ops_since_checkpoint.clear();
+    }
+
+    /// Apply a single previously logged operation. This is the generic counterpart of the typed
+    /// `update_nexus`/`update_pool`/`update_replica`/`update_snapshot` methods above, used to
+    /// replay the operation tail recorded after a [`StateCheckpoint`] was taken.
+    pub(crate) fn apply_op(&mut self, op: StateOp) {
+        match op {
+            StateOp::Nexus(state) => self.update_nexus(state),
+            StateOp::Pool(state) => self.update_pool(state),
+            StateOp::Replica(state) => self.update_replica(state),
+            StateOp::Snapshot(state) => self.update_snapshot(state),
+        }
+    }
+
+    /// Append an applied operation to the in-memory log kept since the last checkpoint.
+    fn log_op(&mut self, op: StateOp) {
+        self.ops_since_checkpoint.push(op);
+    }
+
+    /// True once [`KEEP_STATE_EVERY`] operations have been applied since the last checkpoint,
+    /// meaning a fresh one is due.
+    pub(crate) fn checkpoint_due(&self) -> bool {
+        self.ops_since_checkpoint.len() >= KEEP_STATE_EVERY
+    }
+
+    /// Take a full checkpoint of the current state and reset the operation log, since everything
+    /// it recorded is now captured by the checkpoint itself.
+    pub(crate) fn checkpoint(&mut self) -> StateCheckpoint {
+        let checkpoint = StateCheckpoint {
+            nexuses: self.nexus_states_cloned(),
+            pools: self.pool_states_cloned(),
+            replicas: self.replica_states_cloned(),
+            snapshots: self.snapshot_states_cloned(),
+        };
+        self.ops_since_checkpoint.clear();
+        checkpoint
+    }
+
+    /// Restore a checkpoint and replay the operations applied after it, bringing `Self` back to
+    /// where it was when the log was last truncated (eg before an agent restart).
+    pub(crate) fn restore(&mut self, checkpoint: StateCheckpoint, ops: Vec<StateOp>) {
+        self.clear_all();
+        self.nexuses.populate(checkpoint.nexuses);
+        self.pools.populate(checkpoint.pools);
+        self.replicas.populate(checkpoint.replicas);
+        self.snapshots.populate(checkpoint.snapshots);
+        for op in ops {
+            self.apply_op(op);
+        }
+    }
+
+    /// Subscribe to nexus change events.
+    pub(crate) fn subscribe_nexuses(&self) -> ResourceSubscription<NexusState, NexusId> {
+        ResourceSubscription {
+            rx: self.nexus_tx.subscribe(),
+        }
+    }
+
+    /// Subscribe to pool change events.
+    pub(crate) fn subscribe_pools(&self) -> ResourceSubscription<PoolState, PoolId> {
+        ResourceSubscription {
+            rx: self.pool_tx.subscribe(),
+        }
+    }
+
+    /// Subscribe to replica change events.
+    pub(crate) fn subscribe_replicas(&self) -> ResourceSubscription<ReplicaState, ReplicaId> {
+        ResourceSubscription {
+            rx: self.replica_tx.subscribe(),
+        }
+    }
+
+    /// Subscribe to snapshot change events.
+    pub(crate) fn subscribe_snapshots(
+        &self,
+    ) -> ResourceSubscription<ReplicaSnapshotState, SnapshotId> {
+        ResourceSubscription {
+            rx: self.snapshot_tx.subscribe(),
+        }
+    }
+
+    /// Diff an `old` and `new` full listing of a resource kind's state, keyed by `key_of`,
+    /// producing the per-item deltas that a clear-and-repopulate would otherwise collapse into a
+    /// flat remove-everything/add-everything storm.
+    fn diff<K, S, F>(old: Vec<S>, new: Vec<S>, key_of: F) -> Vec<ResourceDelta<S, K>>
+    where
+        K: std::hash::Hash + Eq,
+        S: PartialEq,
+        F: Fn(&S) -> K,
+    {
+        let mut old_by_id: HashMap<K, S> =
+            old.into_iter().map(|state| (key_of(&state), state)).collect();
+        let mut deltas = Vec::new();
+        for new_state in new {
+            let id = key_of(&new_state);
+            match old_by_id.remove(&id) {
+                Some(old_state) if old_state == new_state => {}
+                Some(old_state) => deltas.push(ResourceDelta::Updated {
+                    old: old_state,
+                    new: new_state,
+                }),
+                None => deltas.push(ResourceDelta::Added(new_state)),
+            }
+        }
+        deltas.extend(old_by_id.into_keys().map(ResourceDelta::Removed));
+        deltas
     }
 
     /// Takes an iterator of resources resourced by an 'Arc' and 'Mutex' and returns a vector of
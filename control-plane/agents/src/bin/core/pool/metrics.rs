@@ -0,0 +1,162 @@
+//! Prometheus metrics for the core agent's pool/replica business logic, in the spirit of Garage's
+//! `admin/metrics.rs`: a small, self-contained module colocated with the code it instruments
+//! rather than bolted on at the gRPC boundary.
+//!
+//! Unlike `grpc::operations::pool::metrics` (which times calls at the gRPC server boundary) and
+//! `controller::registry_metrics::RegistryCollector` (which derives cardinality gauges from the
+//! in-memory resource states on scrape), this module periodically walks the same `node_pools`/
+//! `registry.replicas()` paths [`Service::get_pools`]/[`Service::get_replicas`] use, and exposes
+//! counters incremented directly inside [`Service::create_pool`]/[`Service::destroy_pool`]/
+//! [`Service::create_replica`]/[`Service::share_replica`], so capacity alerts and operation rates
+//! reflect the registry's own view of the world rather than what made it out over gRPC.
+
+use crate::controller::registry::Registry;
+use once_cell::sync::Lazy;
+use prometheus::{register_int_counter_vec, register_int_gauge_vec, IntCounterVec, IntGaugeVec, TextEncoder};
+use std::time::Duration;
+use tracing::error;
+
+/// Per-pool capacity/commitment gauges, labelled by node, pool, and the pool's current status.
+static POOL_CAPACITY_BYTES: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "mayastor_pool_capacity_bytes",
+        "Pool capacity in bytes",
+        &["node", "pool", "status"]
+    )
+    .expect("metric can be registered")
+});
+static POOL_USED_BYTES: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "mayastor_pool_used_bytes",
+        "Pool used bytes",
+        &["node", "pool", "status"]
+    )
+    .expect("metric can be registered")
+});
+static POOL_AVAILABLE_BYTES: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "mayastor_pool_available_bytes",
+        "Pool available (capacity minus used) bytes",
+        &["node", "pool", "status"]
+    )
+    .expect("metric can be registered")
+});
+/// Number of replicas on each pool, labelled the same way as the capacity gauges above so a
+/// dashboard can correlate replica count against remaining capacity.
+static REPLICA_COUNT: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "mayastor_replica_count",
+        "Number of replicas on a pool",
+        &["node", "pool", "status"]
+    )
+    .expect("metric can be registered")
+});
+
+/// Pool create/destroy call counters, incremented inside [`Service::create_pool`] and
+/// [`Service::destroy_pool`].
+static POOL_OPERATIONS_TOTAL: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "mayastor_pool_operations_total",
+        "Total number of pool create/destroy operations handled by the core agent",
+        &["operation", "outcome"]
+    )
+    .expect("metric can be registered")
+});
+/// Replica create/share call counters, incremented inside [`Service::create_replica`] and
+/// [`Service::share_replica`].
+static REPLICA_OPERATIONS_TOTAL: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "mayastor_replica_operations_total",
+        "Total number of replica create/share operations handled by the core agent",
+        &["operation", "outcome"]
+    )
+    .expect("metric can be registered")
+});
+
+/// Record the outcome of a pool `operation` (`"create"` or `"destroy"`).
+pub(super) fn record_pool_operation(operation: &str, ok: bool) {
+    POOL_OPERATIONS_TOTAL
+        .with_label_values(&[operation, if ok { "ok" } else { "error" }])
+        .inc();
+}
+
+/// Record the outcome of a replica `operation` (`"create"` or `"share"`).
+pub(super) fn record_replica_operation(operation: &str, ok: bool) {
+    REPLICA_OPERATIONS_TOTAL
+        .with_label_values(&[operation, if ok { "ok" } else { "error" }])
+        .inc();
+}
+
+/// Encode all registered metrics in the Prometheus text exposition format.
+fn encode() -> String {
+    let metric_families = prometheus::gather();
+    let mut buffer = String::new();
+    if let Err(error) = TextEncoder::new().encode_utf8(&metric_families, &mut buffer) {
+        error!(%error, "Failed to encode core agent pool metrics");
+    }
+    buffer
+}
+
+/// Serve the core agent's metrics on a `/metrics` HTTP endpoint at the given address.
+pub(crate) async fn serve(addr: std::net::SocketAddr) {
+    use hyper::{server::conn::http1, service::service_fn, Request, Response};
+    use hyper_util::rt::TokioIo;
+    use tokio::net::TcpListener;
+
+    let listener = match TcpListener::bind(addr).await {
+        Ok(listener) => listener,
+        Err(error) => {
+            error!(%error, %addr, "Failed to bind core agent metrics listener");
+            return;
+        }
+    };
+    loop {
+        let (stream, _) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(error) => {
+                error!(%error, "Failed to accept core agent metrics connection");
+                continue;
+            }
+        };
+        tokio::task::spawn(async move {
+            let io = TokioIo::new(stream);
+            let service = service_fn(|_req: Request<hyper::body::Incoming>| async move {
+                Ok::<_, std::convert::Infallible>(Response::new(encode()))
+            });
+            if let Err(error) = http1::Builder::new().serve_connection(io, service).await {
+                error!(%error, "Core agent metrics connection error");
+            }
+        });
+    }
+}
+
+/// Periodically refresh the pool/replica gauges by walking the registry the same way
+/// [`Service::get_pools`]/[`Service::get_replicas`] do: every pool on every node via
+/// `get_node_opt_pools`, and every replica via `registry.replicas()`.
+pub(crate) async fn refresh_registry_gauges_periodically(registry: Registry, period: Duration) {
+    let mut interval = tokio::time::interval(period);
+    loop {
+        interval.tick().await;
+
+        let pools = match registry.get_node_opt_pools(None).await {
+            Ok(pools) => pools,
+            Err(error) => {
+                error!(%error, "Failed to refresh core agent pool metrics");
+                continue;
+            }
+        };
+        let replicas = registry.replicas().await;
+
+        for pool in &pools {
+            let Some(state) = pool.state() else { continue };
+            let labels: [&str; 3] = [state.node.as_str(), pool.id().as_str(), &state.status.to_string()];
+            POOL_CAPACITY_BYTES.with_label_values(&labels).set(state.capacity as i64);
+            POOL_USED_BYTES.with_label_values(&labels).set(state.used as i64);
+            let available = state.capacity.saturating_sub(state.used);
+            POOL_AVAILABLE_BYTES.with_label_values(&labels).set(available as i64);
+
+            let replica_count = replicas.iter().filter(|r| r.pool_id == *pool.id()).count();
+            REPLICA_COUNT.with_label_values(&labels).set(replica_count as i64);
+        }
+    }
+}
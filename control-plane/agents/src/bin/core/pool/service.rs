@@ -1,7 +1,8 @@
+use super::metrics;
 use crate::controller::{
     registry::Registry,
     resources::{
-        operations::{ResourceLifecycle, ResourceSharing},
+        operations::{ResourceLifecycle, ResourceSharing, ResourceSnapshotting},
         operations_helper::{OperationSequenceGuard, ResourceSpecsLocked},
         OperationGuardArc, ResourceMutex,
     },
@@ -11,12 +12,19 @@ use agents::errors::{PoolNotFound, ReplicaNotFound, SvcError};
 use grpc::{
     context::Context,
     operations::{
-        pool::traits::{CreatePoolInfo, DestroyPoolInfo, EditPoolInfo, PoolOperations},
+        pool::traits::{
+            CreatePoolInfo, DestroyPoolInfo, EditPoolInfo, LabelPoolInfo, PoolOperations,
+            StartPoolInfo, StopPoolInfo, UnlabelPoolInfo,
+        },
         replica::traits::{
             CreateReplicaInfo, DestroyReplicaInfo, ReplicaOperations, ShareReplicaInfo,
             UnshareReplicaInfo,
         },
+        replica_snapshot::traits::{
+            CreateReplicaSnapshotInfo, DestroyReplicaSnapshotInfo, SnapshotOperations,
+        },
     },
+    poll_timer::PollTimerExt,
 };
 use stor_port::{
     transport_api::{
@@ -26,8 +34,12 @@ use stor_port::{
     types::v0::{
         store::{pool::PoolSpec, replica::ReplicaSpec},
         transport::{
-            CreatePool, CreateReplica, DestroyPool, DestroyReplica, Filter, GetPools, GetReplicas,
-            NodeId, Pool, PoolId, Replica, ShareReplica, UnshareReplica,
+            BatchPoolRequest, BatchPoolReply, BatchReplicaReply, BatchReplicaRequest, CreatePool,
+            CreateReplica, CreateReplicaSnapshot, DestroyPool, DestroyReplica,
+            DestroyReplicaSnapshot, Filter, GetPools, GetReplicas, ListReplicaSnapshots, NodeId,
+            Pool, PoolBatchOp, PoolBatchOpResult, PoolId, Replica, ReplicaBatchOp,
+            ReplicaBatchOpResult, ReplicaSnapshot, ShareReplica, StartPool, StopPool,
+            UnshareReplica,
         },
     },
 };
@@ -48,7 +60,10 @@ impl PoolOperations for Service {
     ) -> Result<Pool, ReplyError> {
         let req = pool.into();
         let service = self.clone();
-        let pool = Context::spawn(async move { service.create_pool(&req).await }).await??;
+        let pool = Context::spawn(
+            async move { service.create_pool(&req).await }.with_poll_timer("create_pool"),
+        )
+        .await??;
         Ok(pool)
     }
 
@@ -59,7 +74,10 @@ impl PoolOperations for Service {
     ) -> Result<Pool, ReplyError> {
         let req = pool.into();
         let service = self.clone();
-        let pool = Context::spawn(async move { service.create_pool(&req).await }).await??;
+        let pool = Context::spawn(
+            async move { service.create_pool(&req).await }.with_poll_timer("patch_pool"),
+        )
+        .await??;
         Ok(pool)
     }
 
@@ -70,15 +88,70 @@ impl PoolOperations for Service {
     ) -> Result<(), ReplyError> {
         let req = pool.into();
         let service = self.clone();
-        Context::spawn(async move { service.destroy_pool(&req).await }).await??;
+        Context::spawn(async move { service.destroy_pool(&req).await }.with_poll_timer("destroy_pool"))
+            .await??;
         Ok(())
     }
 
+    async fn start(
+        &self,
+        pool: &dyn StartPoolInfo,
+        _ctx: Option<Context>,
+    ) -> Result<Pool, ReplyError> {
+        let req = pool.into();
+        let service = self.clone();
+        let pool = Context::spawn(
+            async move { service.start_pool(&req).await }.with_poll_timer("start_pool"),
+        )
+        .await??;
+        Ok(pool)
+    }
+
+    async fn stop(
+        &self,
+        pool: &dyn StopPoolInfo,
+        _ctx: Option<Context>,
+    ) -> Result<Pool, ReplyError> {
+        let req = pool.into();
+        let service = self.clone();
+        let pool = Context::spawn(
+            async move { service.stop_pool(&req).await }.with_poll_timer("stop_pool"),
+        )
+        .await??;
+        Ok(pool)
+    }
+
     async fn get(&self, filter: Filter, _ctx: Option<Context>) -> Result<Pools, ReplyError> {
         let req = GetPools { filter };
         let pools = self.get_pools(&req).await?;
         Ok(pools)
     }
+
+    async fn label(
+        &self,
+        _pool: &dyn LabelPoolInfo,
+        _ctx: Option<Context>,
+    ) -> Result<Pool, ReplyError> {
+        Err(SvcError::Unimplemented {
+            resource: stor_port::transport_api::ResourceKind::Pool,
+            request: "label_pool".to_string(),
+            source: tonic::Status::unimplemented("pool labelling is not yet implemented"),
+        }
+        .into())
+    }
+
+    async fn unlabel(
+        &self,
+        _pool: &dyn UnlabelPoolInfo,
+        _ctx: Option<Context>,
+    ) -> Result<Pool, ReplyError> {
+        Err(SvcError::Unimplemented {
+            resource: stor_port::transport_api::ResourceKind::Pool,
+            request: "unlabel_pool".to_string(),
+            source: tonic::Status::unimplemented("pool unlabelling is not yet implemented"),
+        }
+        .into())
+    }
 }
 
 #[tonic::async_trait]
@@ -90,8 +163,11 @@ impl ReplicaOperations for Service {
     ) -> Result<Replica, ReplyError> {
         let create_replica = req.into();
         let service = self.clone();
-        let replica =
-            Context::spawn(async move { service.create_replica(&create_replica).await }).await??;
+        let replica = Context::spawn(
+            async move { service.create_replica(&create_replica).await }
+                .with_poll_timer("create_replica"),
+        )
+        .await??;
         Ok(replica)
     }
 
@@ -108,7 +184,11 @@ impl ReplicaOperations for Service {
     ) -> Result<(), ReplyError> {
         let destroy_replica = req.into();
         let service = self.clone();
-        Context::spawn(async move { service.destroy_replica(&destroy_replica).await }).await??;
+        Context::spawn(
+            async move { service.destroy_replica(&destroy_replica).await }
+                .with_poll_timer("destroy_replica"),
+        )
+        .await??;
         Ok(())
     }
 
@@ -119,8 +199,11 @@ impl ReplicaOperations for Service {
     ) -> Result<String, ReplyError> {
         let share_replica = req.into();
         let service = self.clone();
-        let response =
-            Context::spawn(async move { service.share_replica(&share_replica).await }).await??;
+        let response = Context::spawn(
+            async move { service.share_replica(&share_replica).await }
+                .with_poll_timer("share_replica"),
+        )
+        .await??;
         Ok(response)
     }
 
@@ -131,7 +214,54 @@ impl ReplicaOperations for Service {
     ) -> Result<(), ReplyError> {
         let unshare_replica = req.into();
         let service = self.clone();
-        Context::spawn(async move { service.unshare_replica(&unshare_replica).await }).await??;
+        Context::spawn(
+            async move { service.unshare_replica(&unshare_replica).await }
+                .with_poll_timer("unshare_replica"),
+        )
+        .await??;
+        Ok(())
+    }
+}
+
+#[tonic::async_trait]
+impl SnapshotOperations for Service {
+    async fn create_replica_snapshot(
+        &self,
+        snapshot: &dyn CreateReplicaSnapshotInfo,
+        _ctx: Option<Context>,
+    ) -> Result<ReplicaSnapshot, ReplyError> {
+        let create_snapshot = snapshot.into();
+        let service = self.clone();
+        let snapshot = Context::spawn(
+            async move { service.create_replica_snapshot(&create_snapshot).await }
+                .with_poll_timer("create_replica_snapshot"),
+        )
+        .await??;
+        Ok(snapshot)
+    }
+
+    async fn list_replica_snapshots(
+        &self,
+        filter: Filter,
+        _ctx: Option<Context>,
+    ) -> Result<Vec<ReplicaSnapshot>, ReplyError> {
+        let req = ListReplicaSnapshots { filter };
+        let snapshots = self.list_replica_snapshots(&req).await?;
+        Ok(snapshots)
+    }
+
+    async fn destroy_replica_snapshot(
+        &self,
+        snapshot: &dyn DestroyReplicaSnapshotInfo,
+        _ctx: Option<Context>,
+    ) -> Result<(), ReplyError> {
+        let destroy_snapshot = snapshot.into();
+        let service = self.clone();
+        Context::spawn(
+            async move { service.destroy_replica_snapshot(&destroy_snapshot).await }
+                .with_poll_timer("destroy_replica_snapshot"),
+        )
+        .await??;
         Ok(())
     }
 }
@@ -273,14 +403,68 @@ impl Service {
     /// Create a pool using the given parameters.
     #[tracing::instrument(level = "info", skip(self), err, fields(pool.id = %request.id))]
     pub(super) async fn create_pool(&self, request: &CreatePool) -> Result<Pool, SvcError> {
-        OperationGuardArc::<PoolSpec>::create(&self.registry, request).await
+        let result = OperationGuardArc::<PoolSpec>::create(&self.registry, request).await;
+        metrics::record_pool_operation("create", result.is_ok());
+        result
     }
 
     /// Destroy a pool using the given parameters.
     #[tracing::instrument(level = "info", skip(self), err, fields(pool.id = %request.id))]
     pub(super) async fn destroy_pool(&self, request: &DestroyPool) -> Result<(), SvcError> {
         let mut pool = self.pool_opt(&request.id).await?;
-        pool.destroy(&self.registry, request).await
+        let result = pool.destroy(&self.registry, request).await;
+        metrics::record_pool_operation("destroy", result.is_ok());
+        result
+    }
+
+    /// Start (import) a previously stopped pool using the given parameters.
+    #[tracing::instrument(level = "info", skip(self), err, fields(pool.id = %request.id))]
+    pub(super) async fn start_pool(&self, request: &StartPool) -> Result<Pool, SvcError> {
+        let mut pool = self.pool_opt(&request.id).await?.context(PoolNotFound {
+            pool_id: request.id.clone(),
+        })?;
+        pool.start(&self.registry, request).await
+    }
+
+    /// Stop a pool using the given parameters, taking it offline without destroying the on-disk
+    /// data. The pool's desired run-state is persisted so it stays down across io-engine
+    /// restarts instead of being auto-reimported.
+    #[tracing::instrument(level = "info", skip(self), err, fields(pool.id = %request.id))]
+    pub(super) async fn stop_pool(&self, request: &StopPool) -> Result<Pool, SvcError> {
+        let mut pool = self.pool_opt(&request.id).await?.context(PoolNotFound {
+            pool_id: request.id.clone(),
+        })?;
+        pool.stop(&self.registry, request).await
+    }
+
+    /// Execute an ordered list of heterogeneous pool operations, one at a time, collecting a
+    /// per-item result so that one failure doesn't abort the rest of the batch.
+    #[tracing::instrument(level = "info", skip(self))]
+    pub(super) async fn batch_pools(&self, request: &BatchPoolRequest) -> BatchPoolReply {
+        let mut results = Vec::with_capacity(request.ops.len());
+        for op in &request.ops {
+            let result = match op {
+                PoolBatchOp::Create(create) => match self.create_pool(create).await {
+                    Ok(pool) => PoolBatchOpResult::Pool(pool),
+                    Err(error) => PoolBatchOpResult::Error(error.into()),
+                },
+                PoolBatchOp::Destroy(destroy) => match self.destroy_pool(destroy).await {
+                    Ok(()) => PoolBatchOpResult::Empty,
+                    Err(error) => PoolBatchOpResult::Error(error.into()),
+                },
+                // Labelling isn't exposed on the pool service yet; report it per-item rather
+                // than aborting the rest of an otherwise valid batch.
+                PoolBatchOp::Label(_) | PoolBatchOp::Unlabel(_) => {
+                    PoolBatchOpResult::Error(SvcError::Unimplemented {
+                        resource: stor_port::transport_api::ResourceKind::Pool,
+                        request: "batch_pools (label)".to_string(),
+                        source: tonic::Status::unimplemented("pool labelling is not yet batched"),
+                    }.into())
+                }
+            };
+            results.push(result);
+        }
+        BatchPoolReply { results }
     }
 
     /// Create a replica using the given parameters.
@@ -289,7 +473,14 @@ impl Service {
         &self,
         request: &CreateReplica,
     ) -> Result<Replica, SvcError> {
-        OperationGuardArc::<ReplicaSpec>::create(&self.registry, request).await
+        let result = OperationGuardArc::<ReplicaSpec>::create(&self.registry, request).await;
+        metrics::record_replica_operation("create", result.is_ok());
+        // This is the right place to feed a successful placement's pool/size into
+        // `volume_policy::balanced::PoolLoadTracker::observe`, once a tracker instance has
+        // somewhere persistent to live - Registry is the natural home (it's already `Clone`/
+        // shared the way the tracker would need to be across calls), but that type isn't part of
+        // this source tree.
+        result
     }
 
     /// Destroy a replica using the given parameters.
@@ -303,7 +494,9 @@ impl Service {
     #[tracing::instrument(level = "info", skip(self), err, fields(replica.uuid = %request.uuid))]
     pub(super) async fn share_replica(&self, request: &ShareReplica) -> Result<String, SvcError> {
         let mut replica = self.specs().replica_opt(&request.uuid).await?;
-        replica.as_mut().share(&self.registry, request).await
+        let result = replica.as_mut().share(&self.registry, request).await;
+        metrics::record_replica_operation("share", result.is_ok());
+        result
     }
 
     /// Unshare a replica using the given parameters.
@@ -313,4 +506,122 @@ impl Service {
         replica.as_mut().unshare(&self.registry, request).await?;
         Ok(())
     }
+
+    /// Maximum number of replica operations run concurrently within a single batch. Bounds how
+    /// many `OperationGuardArc<ReplicaSpec>` guards (and, for creates, the pool guards they imply)
+    /// can be held in flight at once.
+    const BATCH_REPLICA_CONCURRENCY: usize = 8;
+
+    /// Execute a list of heterogeneous replica operations with bounded concurrency, collecting a
+    /// per-item result so that one failure doesn't abort the rest of the batch. This cuts
+    /// round-trips when a volume controller needs to reconcile many replicas at once, e.g. during
+    /// rebuilds or rebalancing.
+    ///
+    /// Operations are run in ascending replica-uuid order, matching the order
+    /// `OperationSequenceGuard` guards are acquired in elsewhere, so that two operations running
+    /// concurrently within the same chunk can never block on each other's guards in opposite
+    /// order. The per-item results preserve the caller's original submission order.
+    #[tracing::instrument(level = "info", skip(self), fields(batch.size = request.ops.len()))]
+    pub(super) async fn batch_replicas(&self, request: &BatchReplicaRequest) -> BatchReplicaReply {
+        let mut order: Vec<usize> = (0..request.ops.len()).collect();
+        order.sort_by_key(|&index| request.ops[index].uuid().clone());
+
+        let mut results: Vec<Option<ReplicaBatchOpResult>> = vec![None; request.ops.len()];
+        for chunk in order.chunks(Self::BATCH_REPLICA_CONCURRENCY) {
+            let handles: Vec<(usize, tokio::task::JoinHandle<ReplicaBatchOpResult>)> = chunk
+                .iter()
+                .map(|&index| {
+                    let service = self.clone();
+                    let op = request.ops[index].clone();
+                    let handle =
+                        tokio::spawn(async move { service.batch_replica_op(op).await });
+                    (index, handle)
+                })
+                .collect();
+
+            for (index, handle) in handles {
+                let result = match handle.await {
+                    Ok(result) => result,
+                    Err(error) => ReplicaBatchOpResult::Error(
+                        SvcError::Internal {
+                            details: format!("batch replica operation task failed: {error}"),
+                        }
+                        .into(),
+                    ),
+                };
+                results[index] = Some(result);
+            }
+        }
+
+        BatchReplicaReply {
+            results: results
+                .into_iter()
+                .map(|result| result.expect("every index is filled exactly once"))
+                .collect(),
+        }
+    }
+
+    /// Run a single operation of a `BatchReplicaRequest`, reusing the same per-operation entry
+    /// points (and therefore the same guard acquisition and metrics) as the unbatched calls.
+    async fn batch_replica_op(&self, op: ReplicaBatchOp) -> ReplicaBatchOpResult {
+        match op {
+            ReplicaBatchOp::Create(create) => match self.create_replica(&create).await {
+                Ok(replica) => ReplicaBatchOpResult::Replica(replica),
+                Err(error) => ReplicaBatchOpResult::Error(error.into()),
+            },
+            ReplicaBatchOp::Destroy(destroy) => match self.destroy_replica(&destroy).await {
+                Ok(()) => ReplicaBatchOpResult::Empty,
+                Err(error) => ReplicaBatchOpResult::Error(error.into()),
+            },
+            ReplicaBatchOp::Share(share) => match self.share_replica(&share).await {
+                Ok(uri) => ReplicaBatchOpResult::Uri(uri),
+                Err(error) => ReplicaBatchOpResult::Error(error.into()),
+            },
+            ReplicaBatchOp::Unshare(unshare) => match self.unshare_replica(&unshare).await {
+                Ok(()) => ReplicaBatchOpResult::Empty,
+                Err(error) => ReplicaBatchOpResult::Error(error.into()),
+            },
+        }
+    }
+
+    /// Create a snapshot of a replica using the given parameters.
+    #[tracing::instrument(level = "info", skip(self), err, fields(replica.uuid = %request.replica))]
+    pub(super) async fn create_replica_snapshot(
+        &self,
+        request: &CreateReplicaSnapshot,
+    ) -> Result<ReplicaSnapshot, SvcError> {
+        let mut replica = self.specs().replica_opt(&request.replica).await?;
+        replica.as_mut().create_snapshot(&self.registry, request).await
+    }
+
+    /// Destroy a replica snapshot using the given parameters.
+    #[tracing::instrument(level = "info", skip(self), err)]
+    pub(super) async fn destroy_replica_snapshot(
+        &self,
+        request: &DestroyReplicaSnapshot,
+    ) -> Result<(), SvcError> {
+        let snapshot = self.registry.replica_snapshot(&request.uuid).await?;
+        let mut replica = self.specs().replica_opt(&snapshot.replica()).await?;
+        replica.as_mut().destroy_snapshot(&self.registry, request).await
+    }
+
+    /// List replica snapshots according to the filter.
+    #[tracing::instrument(level = "info", skip(self), err)]
+    pub(super) async fn list_replica_snapshots(
+        &self,
+        request: &ListReplicaSnapshots,
+    ) -> Result<Vec<ReplicaSnapshot>, SvcError> {
+        let filter = request.filter.clone();
+        match filter {
+            Filter::None => Ok(self.registry.replica_snapshots().await),
+            Filter::Replica(replica_id) | Filter::ReplicaSnapshot(replica_id) => Ok(self
+                .registry
+                .replica_snapshots()
+                .await
+                .into_iter()
+                .filter(|snapshot| snapshot.replica() == replica_id)
+                .collect()),
+            _ => Err(SvcError::InvalidFilter { filter }),
+        }
+    }
 }
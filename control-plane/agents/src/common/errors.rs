@@ -1,6 +1,9 @@
 use snafu::{Error, Snafu};
 use stor_port::{
-    transport_api::{ErrorChain, ReplyError, ReplyErrorKind, ResourceKind},
+    transport_api::{
+        ErrorChain, ErrorDetails, ErrorInfo, QuotaFailure, ReplyError, ReplyErrorKind,
+        ResourceKind, RetryInfo,
+    },
     types::v0::{
         store::definitions::StoreError,
         transport::{
@@ -8,11 +11,13 @@ use stor_port::{
         },
     },
 };
+use strum::{AsRefStr, EnumDiscriminants};
 use tonic::Code;
 
 /// Common error type for send/receive
-#[derive(Debug, Snafu)]
+#[derive(Debug, Snafu, EnumDiscriminants)]
 #[snafu(visibility(pub), context(suffix(false)))]
+#[strum_discriminants(derive(AsRefStr))]
 #[allow(missing_docs)]
 pub enum SvcError {
     #[snafu(display("Failed to get node '{}' from the node agent", node))]
@@ -84,6 +89,11 @@ pub enum SvcError {
     PoolNotLoaded { pool_id: PoolId },
     #[snafu(display("Pool '{}' not found", pool_id))]
     PoolNotFound { pool_id: PoolId },
+    #[snafu(display(
+        "Pool '{}' is encrypted and locked; provide an unlock method to start it",
+        pool_id
+    ))]
+    PoolLocked { pool_id: PoolId },
     #[snafu(display("Disk list should have only 1 device. Received :{:?}", disks))]
     InvalidPoolDeviceNum { disks: Vec<PoolDeviceUri> },
     #[snafu(display("Nexus '{}' not found", nexus_id))]
@@ -265,10 +275,22 @@ pub enum SvcError {
     #[snafu(display("The uuid '{}' for kind '{}' is not valid.", uuid, kind.to_string()))]
     InvalidUuid { uuid: String, kind: ResourceKind },
     #[snafu(display(
-        "Unable to start rebuild. Maximum number of rebuilds permitted is {}",
-        max_rebuilds
+        "Unable to start rebuild on node '{}': {}/{} rebuilds already running",
+        node,
+        running,
+        limit
+    ))]
+    MaxRebuilds {
+        node: String,
+        running: u32,
+        limit: u32,
+    },
+    #[snafu(display(
+        "Rebuild for nexus '{}' queued at position {}",
+        nexus,
+        position
     ))]
-    MaxRebuilds { max_rebuilds: u32 },
+    RebuildQueued { nexus: String, position: u32 },
     #[snafu(display("The api version: {:?} is not valid", api_version))]
     InvalidApiVersion { api_version: Option<ApiVersion> },
     #[snafu(display("The subsystem with nqn: {} is not found, {}", nqn, details))]
@@ -310,6 +332,21 @@ pub enum SvcError {
         replica_ids: Vec<String>,
         required: u64,
     },
+    #[snafu(display("Failed to grow pool '{}'", pool))]
+    PoolGrow { pool: String, source: ReplyError },
+    #[snafu(display("Pool '{}' does not support growing", pool))]
+    PoolGrowUnsupported { pool: String },
+    #[snafu(display(
+        "Pool '{}' grow requested '{}' bytes but the backing device only has '{}' available",
+        pool,
+        requested,
+        available
+    ))]
+    PoolGrowExceedsDevice {
+        pool: String,
+        requested: u64,
+        available: u64,
+    },
     #[snafu(display(
         "Service for request '{}' for '{}' is unimplemented with '{}'",
         request,
@@ -338,6 +375,17 @@ pub enum SvcError {
     },
     #[snafu(display("The service is busy, cannot process request"))]
     ServiceBusy {},
+    #[snafu(display(
+        "{} '{}' is locked by the '{}' subsystem",
+        resource_kind.to_string(),
+        resource_id,
+        subsystem
+    ))]
+    ResourceLocked {
+        subsystem: String,
+        resource_id: String,
+        resource_kind: ResourceKind,
+    },
     #[snafu(display("The service is shutdown, cannot process request"))]
     ServiceShutdown {},
     #[snafu(display("The snapshot is not created, and its parent volume is gone"))]
@@ -362,29 +410,529 @@ pub enum SvcError {
 }
 
 impl SvcError {
-    /// Get comparable `tonic::Code`.
-    /// todo: use existing conversion Self->ReplyError->tonic instead.
+    /// Get the `tonic::Code` for this error. A match without a catch-all, so that a newly added
+    /// variant forces a compile error here instead of silently collapsing to `Internal`; see also
+    /// [`Self::http_code`], which is kept in sync the same way.
     pub fn tonic_code(&self) -> tonic::Code {
+        use tonic::Code;
         match self {
-            Self::NotFound { .. } => tonic::Code::NotFound,
-            Self::NexusNotFound { .. } => tonic::Code::NotFound,
-            Self::PoolNotFound { .. } => tonic::Code::NotFound,
-            Self::ReplicaNotFound { .. } => tonic::Code::NotFound,
-            Self::PoolNotLoaded { .. } => tonic::Code::FailedPrecondition,
-            Self::ChildNotFound { .. } => tonic::Code::NotFound,
-            Self::AlreadyExists { .. } => tonic::Code::AlreadyExists,
+            Self::GetNode { .. } | Self::GetNodes { .. } => Code::Unavailable,
             Self::GrpcRequestError { source, .. } => source.code(),
-            Self::GrpcConnectTimeout { .. } => tonic::Code::DeadlineExceeded,
-            Self::GrpcConnect { .. } => tonic::Code::Unavailable,
-            Self::GrpcUdsConnect { .. } => tonic::Code::Unavailable,
-            Self::Internal { .. } => tonic::Code::Internal,
-            Self::Unimplemented { .. } => tonic::Code::Unimplemented,
-            Self::RestrictedReplicaCount { .. } => tonic::Code::FailedPrecondition,
-            _ => tonic::Code::Internal,
+
+            Self::NodeNotOnline { .. }
+            | Self::NoNodes {}
+            | Self::GrpcConnect { .. }
+            | Self::GrpcUdsConnect { .. }
+            | Self::PendingCreation { .. }
+            | Self::PendingDeletion { .. }
+            | Self::Deleting { .. }
+            | Self::NotReady { .. }
+            | Self::NoOnlineReplicas { .. }
+            | Self::NoHealthyReplicas { .. }
+            | Self::NvmeConnectError { .. }
+            | Self::ReplicaSnapSkipped { .. }
+            | Self::ReplicaSnapMiss { .. }
+            | Self::ServiceBusy {}
+            | Self::ServiceShutdown {}
+            | Self::ResourceLocked { .. }
+            | Self::RebuildQueued { .. }
+            | Self::SnapshotMaxTransactions { .. } => Code::Unavailable,
+
+            Self::GrpcConnectTimeout { .. } => Code::DeadlineExceeded,
+
+            Self::NodeNotFound { .. }
+            | Self::PoolNotFound { .. }
+            | Self::NexusNotFound { .. }
+            | Self::VolSnapshotNotFound { .. }
+            | Self::NotFound { .. }
+            | Self::ChildNotFound { .. }
+            | Self::VolumeNotFound { .. }
+            | Self::AffinityGroupNotFound { .. }
+            | Self::ReplicaNotFound { .. }
+            | Self::WatchNotFound {}
+            | Self::WatchResourceNotFound { .. }
+            | Self::RebuildHistoryNotFound { .. }
+            | Self::StoreMissingEntry { .. }
+            | Self::SubsystemNotFound { .. } => Code::NotFound,
+
+            Self::ChildAlreadyExists { .. }
+            | Self::VolumeAlreadyPublished { .. }
+            | Self::AlreadyShared { .. }
+            | Self::WatchAlreadyExists {}
+            | Self::ReCreateMismatch { .. }
+            | Self::InUse { .. }
+            | Self::Conflict {}
+            | Self::AlreadyExists { .. } => Code::Aborted,
+
+            Self::NodeGrpcEndpoint { .. }
+            | Self::GrpcConnectUri { .. }
+            | Self::InvalidPoolDeviceNum { .. }
+            | Self::InvalidSnapshotSource { .. }
+            | Self::InvalidShareProtocol { .. }
+            | Self::VolumeResizeArgsInvalid { .. }
+            | Self::InvalidFilter { .. }
+            | Self::InvalidArguments {}
+            | Self::InvalidLabel { .. }
+            | Self::RestrictedReplicaCount { .. }
+            | Self::InvalidUuid { .. }
+            | Self::InvalidApiVersion { .. }
+            | Self::NvmeParseError {}
+            | Self::NReplSnapshotNotAllowed {}
+            | Self::NReplSnapshotCloneCreationNotAllowed {}
+            | Self::ClonedSnapshotVolumeThin {}
+            | Self::ClonedSnapshotVolumeSize {}
+            | Self::ClonedSnapshotVolumeRepl {} => Code::InvalidArgument,
+
+            Self::FrontendNodeNotAllowed { .. } => Code::PermissionDenied,
+
+            Self::NotEnoughResources { .. }
+            | Self::NoSnapshotPools { .. }
+            | Self::MaxRebuilds { .. }
+            | Self::NoCapacityToOnline { .. } => Code::ResourceExhausted,
+
+            Self::CordonedNode { .. }
+            | Self::CordonLabel { .. }
+            | Self::UncordonLabel { .. }
+            | Self::PoolNotLoaded { .. }
+            | Self::PoolLocked { .. }
+            | Self::VolumeNotPublished { .. }
+            | Self::NotShared { .. }
+            | Self::MultipleNexuses {}
+            | Self::LastReplica { .. }
+            | Self::LastHealthyReplica { .. }
+            | Self::ReplicaCountAchieved { .. }
+            | Self::ReplicaChangeCount {}
+            | Self::ReplicaIncrease { .. }
+            | Self::ReplicaRemovalNoCandidates { .. }
+            | Self::ReplicaCreateNumber { .. }
+            | Self::ResizeReplError { .. }
+            | Self::PoolGrow { .. }
+            | Self::SnapshotNotCreatedNoVolume {}
+            | Self::SnapshotNotCreated {}
+            | Self::DrainNotAllowedWhenHAisDisabled {}
+            | Self::SwitchoverNotAllowedWhenHAisDisabled {} => Code::FailedPrecondition,
+
+            Self::Unimplemented { .. } | Self::PoolGrowUnsupported { .. } => Code::Unimplemented,
+
+            Self::PoolGrowExceedsDevice { .. } => Code::OutOfRange,
+
+            Self::JsonRpcDeserialise { .. }
+            | Self::JsonRpc { .. }
+            | Self::Internal { .. }
+            | Self::Store { .. }
+            | Self::StoreDirty { .. }
+            | Self::UnexpectedSubsystemNqn { .. }
+            | Self::ReplicaSnapError { .. } => Code::Internal,
+        }
+    }
+
+    /// Get the `http::StatusCode` for this error, for the REST/OpenAPI layer. A match without a
+    /// catch-all, so that a newly added variant forces a compile error here instead of silently
+    /// collapsing to 500; kept in sync with [`Self::tonic_code`] above.
+    pub fn http_code(&self) -> http::StatusCode {
+        use http::StatusCode as Http;
+        match self {
+            Self::GrpcRequestError { source, .. } => http_status_of_tonic_code(source.code()),
+
+            Self::GetNode { .. }
+            | Self::GetNodes { .. }
+            | Self::NodeNotOnline { .. }
+            | Self::NoNodes {}
+            | Self::GrpcConnect { .. }
+            | Self::GrpcUdsConnect { .. }
+            | Self::PendingCreation { .. }
+            | Self::PendingDeletion { .. }
+            | Self::Deleting { .. }
+            | Self::NotReady { .. }
+            | Self::NoOnlineReplicas { .. }
+            | Self::NoHealthyReplicas { .. }
+            | Self::NvmeConnectError { .. }
+            | Self::ReplicaSnapSkipped { .. }
+            | Self::ReplicaSnapMiss { .. }
+            | Self::ServiceBusy {}
+            | Self::ServiceShutdown {}
+            | Self::ResourceLocked { .. }
+            | Self::RebuildQueued { .. }
+            | Self::SnapshotMaxTransactions { .. } => Http::SERVICE_UNAVAILABLE,
+
+            Self::GrpcConnectTimeout { .. } => Http::GATEWAY_TIMEOUT,
+
+            Self::NodeNotFound { .. }
+            | Self::PoolNotFound { .. }
+            | Self::NexusNotFound { .. }
+            | Self::VolSnapshotNotFound { .. }
+            | Self::NotFound { .. }
+            | Self::ChildNotFound { .. }
+            | Self::VolumeNotFound { .. }
+            | Self::AffinityGroupNotFound { .. }
+            | Self::ReplicaNotFound { .. }
+            | Self::WatchNotFound {}
+            | Self::WatchResourceNotFound { .. }
+            | Self::RebuildHistoryNotFound { .. }
+            | Self::StoreMissingEntry { .. }
+            | Self::SubsystemNotFound { .. } => Http::NOT_FOUND,
+
+            Self::ChildAlreadyExists { .. }
+            | Self::VolumeAlreadyPublished { .. }
+            | Self::AlreadyShared { .. }
+            | Self::WatchAlreadyExists {}
+            | Self::ReCreateMismatch { .. }
+            | Self::InUse { .. }
+            | Self::Conflict {}
+            | Self::AlreadyExists { .. } => Http::CONFLICT,
+
+            Self::NodeGrpcEndpoint { .. }
+            | Self::GrpcConnectUri { .. }
+            | Self::InvalidPoolDeviceNum { .. }
+            | Self::InvalidSnapshotSource { .. }
+            | Self::InvalidShareProtocol { .. }
+            | Self::VolumeResizeArgsInvalid { .. }
+            | Self::InvalidFilter { .. }
+            | Self::InvalidArguments {}
+            | Self::InvalidLabel { .. }
+            | Self::RestrictedReplicaCount { .. }
+            | Self::InvalidUuid { .. }
+            | Self::InvalidApiVersion { .. }
+            | Self::NvmeParseError {}
+            | Self::NReplSnapshotNotAllowed {}
+            | Self::NReplSnapshotCloneCreationNotAllowed {}
+            | Self::ClonedSnapshotVolumeThin {}
+            | Self::ClonedSnapshotVolumeSize {}
+            | Self::ClonedSnapshotVolumeRepl {} => Http::BAD_REQUEST,
+
+            Self::FrontendNodeNotAllowed { .. } => Http::FORBIDDEN,
+
+            Self::NotEnoughResources { .. } | Self::NoSnapshotPools { .. } | Self::NoCapacityToOnline { .. } => {
+                Http::INSUFFICIENT_STORAGE
+            }
+            Self::MaxRebuilds { .. } => Http::TOO_MANY_REQUESTS,
+
+            Self::CordonedNode { .. }
+            | Self::CordonLabel { .. }
+            | Self::UncordonLabel { .. }
+            | Self::PoolNotLoaded { .. }
+            | Self::PoolLocked { .. }
+            | Self::VolumeNotPublished { .. }
+            | Self::NotShared { .. }
+            | Self::MultipleNexuses {}
+            | Self::LastReplica { .. }
+            | Self::LastHealthyReplica { .. }
+            | Self::ReplicaCountAchieved { .. }
+            | Self::ReplicaChangeCount {}
+            | Self::ReplicaIncrease { .. }
+            | Self::ReplicaRemovalNoCandidates { .. }
+            | Self::ReplicaCreateNumber { .. }
+            | Self::ResizeReplError { .. }
+            | Self::PoolGrow { .. }
+            | Self::SnapshotNotCreatedNoVolume {}
+            | Self::SnapshotNotCreated {}
+            | Self::DrainNotAllowedWhenHAisDisabled {}
+            | Self::SwitchoverNotAllowedWhenHAisDisabled {} => Http::PRECONDITION_FAILED,
+
+            Self::Unimplemented { .. } | Self::PoolGrowUnsupported { .. } => Http::NOT_IMPLEMENTED,
+
+            Self::PoolGrowExceedsDevice { .. } => Http::BAD_REQUEST,
+
+            Self::JsonRpcDeserialise { .. }
+            | Self::JsonRpc { .. }
+            | Self::Internal { .. }
+            | Self::Store { .. }
+            | Self::StoreDirty { .. }
+            | Self::UnexpectedSubsystemNqn { .. }
+            | Self::ReplicaSnapError { .. } => Http::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// Classify this error so that reconcilers and gRPC clients can decide whether it's worth
+    /// retrying without having to pattern-match individual variants themselves.
+    ///
+    /// Modelled on the categorized error enum in the Garage crate: every variant lands in exactly
+    /// one of a small set of buckets (internal vs. "cannot process right now" vs. "bad request"),
+    /// rather than callers having to know which of ~90 variants are safe to retry.
+    pub fn category(&self) -> ErrorCategory {
+        use ErrorCategory::*;
+        match self {
+            Self::GetNode { source, .. } => source.category(),
+            Self::GetNodes { source } => source.category(),
+            Self::GrpcRequestError { source, .. } => category_of_code(source.code()),
+
+            // Connectivity/backpressure: the same request is likely to succeed shortly.
+            Self::NodeNotOnline { .. }
+            | Self::GrpcConnectTimeout { .. }
+            | Self::GrpcConnect { .. }
+            | Self::GrpcUdsConnect { .. }
+            | Self::Conflict {} // "Conflicts with existing operation - please retry".
+            | Self::Deleting { .. } // "Resource pending deletion - please retry".
+            | Self::NotReady { .. }
+            | Self::PendingCreation { .. }
+            | Self::PendingDeletion { .. }
+            | Self::ServiceBusy {}
+            | Self::ServiceShutdown {}
+            | Self::ResourceLocked { .. }
+            | Self::RebuildQueued { .. }
+            | Self::SnapshotMaxTransactions { .. }
+            | Self::NoOnlineReplicas { .. }
+            | Self::NoHealthyReplicas { .. }
+            | Self::NvmeConnectError { .. }
+            | Self::ReplicaSnapSkipped { .. }
+            | Self::ReplicaSnapMiss { .. } => Transient,
+
+            // Not enough of some resource right now, but more may free up later.
+            Self::NoNodes {}
+            | Self::NotEnoughResources { .. }
+            | Self::MaxRebuilds { .. }
+            | Self::NoCapacityToOnline { .. }
+            | Self::NoSnapshotPools { .. } => ResourceExhausted,
+
+            // A resource that genuinely doesn't exist (yet, or any more).
+            Self::NodeNotFound { .. }
+            | Self::PoolNotFound { .. }
+            | Self::NexusNotFound { .. }
+            | Self::VolSnapshotNotFound { .. }
+            | Self::NotFound { .. }
+            | Self::ChildNotFound { .. }
+            | Self::VolumeNotFound { .. }
+            | Self::AffinityGroupNotFound { .. }
+            | Self::ReplicaNotFound { .. }
+            | Self::WatchNotFound {}
+            | Self::WatchResourceNotFound { .. }
+            | Self::RebuildHistoryNotFound { .. }
+            | Self::StoreMissingEntry { .. }
+            | Self::SubsystemNotFound { .. } => NotFound,
+
+            // The request clashes with another resource/operation already in that state.
+            Self::ChildAlreadyExists { .. }
+            | Self::VolumeAlreadyPublished { .. }
+            | Self::AlreadyShared { .. }
+            | Self::WatchAlreadyExists {}
+            | Self::ReCreateMismatch { .. }
+            | Self::InUse { .. }
+            | Self::AlreadyExists { .. } => Conflict,
+
+            // Malformed or disallowed input: retrying the exact same request will never help.
+            Self::NodeGrpcEndpoint { .. }
+            | Self::GrpcConnectUri { .. }
+            | Self::InvalidPoolDeviceNum { .. }
+            | Self::InvalidSnapshotSource { .. }
+            | Self::FrontendNodeNotAllowed { .. }
+            | Self::InvalidShareProtocol { .. }
+            | Self::VolumeResizeArgsInvalid { .. }
+            | Self::InvalidFilter { .. }
+            | Self::InvalidArguments {}
+            | Self::InvalidLabel { .. }
+            | Self::RestrictedReplicaCount { .. }
+            | Self::InvalidUuid { .. }
+            | Self::InvalidApiVersion { .. }
+            | Self::NvmeParseError {}
+            | Self::Unimplemented { .. }
+            | Self::NReplSnapshotNotAllowed {}
+            | Self::NReplSnapshotCloneCreationNotAllowed {}
+            | Self::ClonedSnapshotVolumeThin {}
+            | Self::ClonedSnapshotVolumeSize {}
+            | Self::ClonedSnapshotVolumeRepl {}
+            | Self::DrainNotAllowedWhenHAisDisabled {}
+            | Self::SwitchoverNotAllowedWhenHAisDisabled {}
+            | Self::PoolGrowUnsupported { .. }
+            | Self::PoolGrowExceedsDevice { .. } => BadRequest,
+
+            // The target resource exists but is in a state that first needs to change, usually
+            // by another in-flight operation or reconcile loop completing.
+            Self::CordonedNode { .. }
+            | Self::CordonLabel { .. }
+            | Self::PoolNotLoaded { .. }
+            | Self::PoolLocked { .. }
+            | Self::UncordonLabel { .. }
+            | Self::VolumeNotPublished { .. }
+            | Self::NotShared { .. }
+            | Self::MultipleNexuses {}
+            | Self::LastReplica { .. }
+            | Self::LastHealthyReplica { .. }
+            | Self::ReplicaCountAchieved { .. }
+            | Self::ReplicaChangeCount {}
+            | Self::ReplicaIncrease { .. }
+            | Self::ReplicaRemovalNoCandidates { .. }
+            | Self::ReplicaCreateNumber { .. }
+            | Self::ResizeReplError { .. }
+            | Self::PoolGrow { .. }
+            | Self::SnapshotNotCreatedNoVolume {}
+            | Self::SnapshotNotCreated {} => Precondition,
+
+            // Bugs, or failures in our own bookkeeping rather than the request itself.
+            Self::JsonRpcDeserialise { .. }
+            | Self::JsonRpc { .. }
+            | Self::Internal { .. }
+            | Self::Store { .. }
+            | Self::StoreDirty { .. }
+            | Self::UnexpectedSubsystemNqn { .. }
+            | Self::ReplicaSnapError { .. } => Internal,
+        }
+    }
+
+    /// Convenience for the common case: is this worth an automatic retry with backoff, or should
+    /// the caller fail fast? Only truly transient/resource-exhaustion categories are retryable;
+    /// bad requests and conflicts need the caller (or the user) to change something first.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self.category(),
+            ErrorCategory::Transient | ErrorCategory::ResourceExhausted
+        )
+    }
+}
+
+/// Broad classification of an [`SvcError`]/[`ReplyError`], used to decide retry behaviour without
+/// pattern-matching individual error variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCategory {
+    /// A bug, or a failure in our own bookkeeping rather than in the request itself.
+    Internal,
+    /// The target resource exists but needs to reach a different state first.
+    Precondition,
+    /// The request itself is malformed or disallowed; retrying as-is will never succeed.
+    BadRequest,
+    /// Not enough of some resource is available right now.
+    ResourceExhausted,
+    /// The request clashes with another resource/operation already in that state.
+    Conflict,
+    /// The target resource doesn't exist.
+    NotFound,
+    /// Connectivity or backpressure; the same request is likely to succeed shortly.
+    Transient,
+}
+
+/// Maps a gRPC status code to an [`ErrorCategory`], for errors (like
+/// [`SvcError::GrpcRequestError`]) whose category depends on what the remote end returned rather
+/// than on the local variant alone.
+fn category_of_code(code: tonic::Code) -> ErrorCategory {
+    match code {
+        Code::InvalidArgument | Code::OutOfRange | Code::Unimplemented => ErrorCategory::BadRequest,
+        Code::PermissionDenied | Code::Unauthenticated => ErrorCategory::BadRequest,
+        Code::NotFound => ErrorCategory::NotFound,
+        Code::AlreadyExists | Code::Aborted => ErrorCategory::Conflict,
+        Code::ResourceExhausted => ErrorCategory::ResourceExhausted,
+        Code::FailedPrecondition => ErrorCategory::Precondition,
+        Code::DeadlineExceeded | Code::Unavailable | Code::Cancelled => ErrorCategory::Transient,
+        Code::Ok | Code::Unknown | Code::Internal | Code::DataLoss => ErrorCategory::Internal,
+    }
+}
+
+/// Extends [`ReplyError`] with the same retry classification as [`SvcError::category`], so
+/// clients on the other side of the wire (who only ever see the flattened `ReplyError`) can back
+/// off on transient/exhausted categories and fail fast on bad-request/conflict, instead of the
+/// scattered per-[`ReplyErrorKind`] retry checks this used to need.
+pub trait ReplyErrorExt {
+    /// See [`SvcError::category`].
+    fn category(&self) -> ErrorCategory;
+    /// See [`SvcError::is_retryable`].
+    fn is_retryable(&self) -> bool;
+    /// Is this specific `ReplyError` worth an automatic retry?
+    ///
+    /// Unlike [`Self::is_retryable`] (which reuses [`Self::category`]), this classifies directly
+    /// by [`ReplyErrorKind`] per the reconciler/CLI's actionable retry contract: `Unavailable`
+    /// (which covers [`SvcError::ServiceShutdown`]), `Aborted` (which covers
+    /// [`SvcError::ServiceBusy`]), `ResourceExhausted`, `DeadlineExceeded` and `RebuildQueued`
+    /// are retryable; `InvalidArgument`, `NotFound`,
+    /// `AlreadyExists`, `OutOfRange`, `PermissionDenied` and `Unimplemented` are terminal.
+    fn is_retryable_kind(&self) -> bool;
+    /// How long the caller should wait before retrying, if [`Self::is_retryable_kind`].
+    ///
+    /// Prefers a decoded gRPC [`RetryInfo`] detail when present, otherwise falls back to a
+    /// default back-off for the handful of kinds known to need one (`MaxRebuilds`'s
+    /// `ResourceExhausted`, `ServiceBusy`'s `Aborted` and `RebuildQueued`), and `None` for everything else
+    /// retryable (eg plain `Unavailable`/`DeadlineExceeded`, where the caller should use its own
+    /// backoff policy).
+    fn retry_after(&self) -> Option<std::time::Duration>;
+}
+
+impl ReplyErrorExt for ReplyError {
+    fn category(&self) -> ErrorCategory {
+        use ErrorCategory::*;
+        match self.kind {
+            ReplyErrorKind::NotFound => NotFound,
+            ReplyErrorKind::AlreadyExists
+            | ReplyErrorKind::AlreadyPublished
+            | ReplyErrorKind::AlreadyShared
+            | ReplyErrorKind::InUse
+            | ReplyErrorKind::Deleting
+            | ReplyErrorKind::Conflict => Conflict,
+            ReplyErrorKind::NotShared
+            | ReplyErrorKind::NotPublished
+            | ReplyErrorKind::FailedPrecondition
+            | ReplyErrorKind::ReplicaChangeCount
+            | ReplyErrorKind::ReplicaCountAchieved
+            | ReplyErrorKind::ReplicaCreateNumber
+            | ReplyErrorKind::ReplicaIncrease
+            | ReplyErrorKind::VolumeNoReplicas => Precondition,
+            ReplyErrorKind::InvalidArgument
+            | ReplyErrorKind::OutOfRange
+            | ReplyErrorKind::PermissionDenied
+            | ReplyErrorKind::Unauthenticated
+            | ReplyErrorKind::Unimplemented => BadRequest,
+            ReplyErrorKind::ResourceExhausted => ResourceExhausted,
+            ReplyErrorKind::Unavailable
+            | ReplyErrorKind::Aborted
+            | ReplyErrorKind::Timeout
+            | ReplyErrorKind::DeadlineExceeded
+            | ReplyErrorKind::ResourceLocked
+            | ReplyErrorKind::RebuildQueued => Transient,
+            ReplyErrorKind::Internal | ReplyErrorKind::FailedPersist => Internal,
+            // Conservatively fail fast on any kind this crate doesn't know about yet.
+            _ => Internal,
+        }
+    }
+
+    fn is_retryable(&self) -> bool {
+        matches!(
+            self.category(),
+            ErrorCategory::Transient | ErrorCategory::ResourceExhausted
+        )
+    }
+
+    fn is_retryable_kind(&self) -> bool {
+        matches!(
+            self.kind,
+            ReplyErrorKind::Unavailable
+                | ReplyErrorKind::Aborted
+                | ReplyErrorKind::ResourceExhausted
+                | ReplyErrorKind::DeadlineExceeded
+                | ReplyErrorKind::RebuildQueued
+        )
+    }
+
+    fn retry_after(&self) -> Option<std::time::Duration> {
+        if let Some(retry_info) = self.details.as_ref().and_then(|d| d.retry_info) {
+            return Some(retry_info.retry_after);
+        }
+        if !self.is_retryable_kind() {
+            return None;
+        }
+        match self.code.as_str() {
+            "MaxRebuilds" | "ServiceBusy" | "RebuildQueued" => Some(DEFAULT_RETRY_AFTER),
+            _ => None,
         }
     }
 }
 
+/// The standard gRPC-to-HTTP status mapping, used for [`SvcError::http_code`]'s delegate arms
+/// (eg [`SvcError::GrpcRequestError`]) whose code depends on what the remote end returned.
+fn http_status_of_tonic_code(code: tonic::Code) -> http::StatusCode {
+    use http::StatusCode as Http;
+    match code {
+        Code::Ok => Http::OK,
+        Code::Cancelled => Http::from_u16(499).expect("499 is a valid status code"),
+        Code::Unknown | Code::Internal | Code::DataLoss => Http::INTERNAL_SERVER_ERROR,
+        Code::InvalidArgument | Code::OutOfRange => Http::BAD_REQUEST,
+        Code::DeadlineExceeded => Http::GATEWAY_TIMEOUT,
+        Code::NotFound => Http::NOT_FOUND,
+        Code::AlreadyExists | Code::Aborted => Http::CONFLICT,
+        Code::PermissionDenied => Http::FORBIDDEN,
+        Code::Unauthenticated => Http::UNAUTHORIZED,
+        Code::ResourceExhausted => Http::TOO_MANY_REQUESTS,
+        Code::FailedPrecondition => Http::PRECONDITION_FAILED,
+        Code::Unimplemented => Http::NOT_IMPLEMENTED,
+        Code::Unavailable => Http::SERVICE_UNAVAILABLE,
+    }
+}
+
 impl From<StoreError> for SvcError {
     fn from(source: StoreError) -> Self {
         match source {
@@ -411,74 +959,100 @@ impl From<SvcError> for ReplyError {
         #[allow(deprecated)]
         let source = error.description();
         let source = format!("{source}: {error}");
-        let extra = error.parent_full_string();
+        let extra = error.full_string();
+        let error_kind = SvcErrorDiscriminants::from(&error);
+        // Stable, variant-derived identifier for clients to branch on without string-matching
+        // `source`'s human-readable text (eg "VolumeAlreadyPublished", "NoCapacityToOnline").
+        let code = error_kind.as_ref().to_string();
 
-        match error {
+        let reply = match error {
             SvcError::StoreDirty { kind, .. } => ReplyError {
                 kind: ReplyErrorKind::FailedPersist,
                 resource: kind,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::NotShared { kind, .. } => ReplyError {
                 kind: ReplyErrorKind::NotShared,
                 resource: kind,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::AlreadyShared { kind, .. } => ReplyError {
                 kind: ReplyErrorKind::AlreadyShared,
                 resource: kind,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::InvalidShareProtocol { kind, .. } => ReplyError {
                 kind: ReplyErrorKind::InvalidArgument,
                 resource: kind,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::ChildNotFound { .. } => ReplyError {
                 kind: ReplyErrorKind::NotFound,
                 resource: ResourceKind::Child,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::ChildAlreadyExists { .. } => ReplyError {
                 kind: ReplyErrorKind::AlreadyExists,
                 resource: ResourceKind::Child,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::InUse { kind, id } => ReplyError {
                 kind: ReplyErrorKind::InUse,
                 resource: kind,
                 source,
                 extra: format!("id: {id}"),
+                code: code.clone(),
+                details: None,
             },
             SvcError::AlreadyExists { kind, id } => ReplyError {
                 kind: ReplyErrorKind::AlreadyExists,
                 resource: kind,
                 source,
                 extra: format!("id: {id}"),
+                code: code.clone(),
+                details: None,
             },
             SvcError::NotReady { ref kind, .. } => ReplyError {
                 kind: ReplyErrorKind::Unavailable,
                 resource: kind.clone(),
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::Conflict { .. } => ReplyError {
                 kind: ReplyErrorKind::Conflict,
                 resource: ResourceKind::Unknown,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::Deleting { kind } => ReplyError {
                 kind: ReplyErrorKind::Deleting,
                 resource: kind,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::ReCreateMismatch {
                 id: _, ref kind, ..
@@ -487,6 +1061,8 @@ impl From<SvcError> for ReplyError {
                 resource: kind.clone(),
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::GetNode { source, .. } => source,
             SvcError::GetNodes { source } => source,
@@ -505,6 +1081,8 @@ impl From<SvcError> for ReplyError {
                 resource: ResourceKind::Unknown,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
 
             SvcError::NodeNotOnline { .. } => ReplyError {
@@ -512,12 +1090,16 @@ impl From<SvcError> for ReplyError {
                 resource: ResourceKind::Node,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::NodeGrpcEndpoint { .. } => ReplyError {
                 kind: ReplyErrorKind::Internal,
                 resource: ResourceKind::Node,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
 
             SvcError::NoNodes { .. } => ReplyError {
@@ -525,6 +1107,8 @@ impl From<SvcError> for ReplyError {
                 resource: ResourceKind::Node,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
 
             SvcError::CordonedNode { .. } => ReplyError {
@@ -532,6 +1116,8 @@ impl From<SvcError> for ReplyError {
                 resource: ResourceKind::Node,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
 
             SvcError::CordonLabel { .. } => ReplyError {
@@ -539,6 +1125,8 @@ impl From<SvcError> for ReplyError {
                 resource: ResourceKind::Node,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
 
             SvcError::UncordonLabel { .. } => ReplyError {
@@ -546,6 +1134,8 @@ impl From<SvcError> for ReplyError {
                 resource: ResourceKind::Node,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
 
             SvcError::GrpcConnectTimeout { .. } => ReplyError {
@@ -553,6 +1143,8 @@ impl From<SvcError> for ReplyError {
                 resource: ResourceKind::Node,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
 
             SvcError::GrpcConnectUri { .. } => ReplyError {
@@ -560,6 +1152,8 @@ impl From<SvcError> for ReplyError {
                 resource: ResourceKind::Node,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
 
             SvcError::GrpcConnect { .. } => ReplyError {
@@ -567,417 +1161,614 @@ impl From<SvcError> for ReplyError {
                 resource: ResourceKind::Node,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
 
             SvcError::NotEnoughResources { source: rsource } => ReplyError {
                 kind: ReplyErrorKind::ResourceExhausted,
-                resource: match rsource {
+                resource: match &rsource {
                     NotEnough::OfPools { .. } => ResourceKind::Pool,
                     NotEnough::OfReplicas { .. } => ResourceKind::Replica,
                     NotEnough::OfNexuses { .. } => ResourceKind::Nexus,
                     NotEnough::OfNodes { .. } => ResourceKind::Node,
-                    NotEnough::PoolFree {} => ResourceKind::Pool,
+                    NotEnough::PoolFree { .. } => ResourceKind::Pool,
+                    NotEnough::ReplicaCapacity { .. } => ResourceKind::Pool,
+                    NotEnough::ReplicaSpread { .. } => ResourceKind::Pool,
                 },
                 source,
                 extra,
+                code: code.clone(),
+                // `rsource`'s own Display already carries the have/need (and, for
+                // `ReplicaCapacity`/`ReplicaSpread`, available/domains) counts, so it doubles as
+                // the quota-subject clients need without re-deriving them here.
+                details: Some(ErrorDetails {
+                    quota_failure: Some(QuotaFailure {
+                        subject: rsource.to_string(),
+                    }),
+                    ..Default::default()
+                }),
             },
             SvcError::JsonRpcDeserialise { .. } => ReplyError {
                 kind: ReplyErrorKind::Internal,
                 resource: ResourceKind::JsonGrpc,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::Store { .. } => ReplyError {
                 kind: ReplyErrorKind::FailedPersist,
                 resource: ResourceKind::Unknown,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::StoreMissingEntry { .. } => ReplyError {
                 kind: ReplyErrorKind::NotFound,
                 resource: ResourceKind::Unknown,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::JsonRpc { .. } => ReplyError {
                 kind: ReplyErrorKind::Internal,
                 resource: ResourceKind::JsonGrpc,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::NodeNotFound { .. } => ReplyError {
                 kind: ReplyErrorKind::NotFound,
                 resource: ResourceKind::Node,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::PoolNotFound { .. } => ReplyError {
                 kind: ReplyErrorKind::NotFound,
                 resource: ResourceKind::Pool,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::PoolNotLoaded { .. } => ReplyError {
                 kind: ReplyErrorKind::FailedPrecondition,
                 resource: ResourceKind::Pool,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
+            },
+            SvcError::PoolLocked { .. } => ReplyError {
+                kind: ReplyErrorKind::FailedPrecondition,
+                resource: ResourceKind::Pool,
+                source,
+                extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::InvalidPoolDeviceNum { .. } => ReplyError {
                 kind: ReplyErrorKind::InvalidArgument,
                 resource: ResourceKind::Pool,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::ReplicaNotFound { .. } => ReplyError {
                 kind: ReplyErrorKind::NotFound,
                 resource: ResourceKind::Replica,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::NexusNotFound { .. } => ReplyError {
                 kind: ReplyErrorKind::NotFound,
                 resource: ResourceKind::Nexus,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::NotFound { ref kind, .. } => ReplyError {
                 kind: ReplyErrorKind::NotFound,
                 resource: kind.clone(),
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::PendingCreation { ref kind, .. } => ReplyError {
                 kind: ReplyErrorKind::FailedPrecondition,
                 resource: kind.clone(),
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::PendingDeletion { ref kind, .. } => ReplyError {
                 kind: ReplyErrorKind::FailedPrecondition,
                 resource: kind.clone(),
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::VolumeNotFound { .. } => ReplyError {
                 kind: ReplyErrorKind::NotFound,
                 resource: ResourceKind::Volume,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::VolumeNotPublished { .. } => ReplyError {
                 kind: ReplyErrorKind::NotPublished,
                 resource: ResourceKind::Volume,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::VolumeAlreadyPublished { .. } => ReplyError {
                 kind: ReplyErrorKind::AlreadyPublished,
                 resource: ResourceKind::Volume,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::FrontendNodeNotAllowed { .. } => ReplyError {
                 kind: ReplyErrorKind::PermissionDenied,
                 resource: ResourceKind::Volume,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::WatchResourceNotFound { kind } => ReplyError {
                 kind: ReplyErrorKind::NotFound,
                 resource: kind,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::WatchNotFound { .. } => ReplyError {
                 kind: ReplyErrorKind::NotFound,
                 resource: ResourceKind::Watch,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::WatchAlreadyExists { .. } => ReplyError {
                 kind: ReplyErrorKind::AlreadyExists,
                 resource: ResourceKind::Watch,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::InvalidFilter { .. } => ReplyError {
                 kind: ReplyErrorKind::Internal,
                 resource: ResourceKind::Unknown,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::Internal { .. } => ReplyError {
                 kind: ReplyErrorKind::Internal,
                 resource: ResourceKind::Unknown,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::InvalidLabel { resource_kind, .. } => ReplyError {
                 kind: ReplyErrorKind::InvalidArgument,
                 resource: resource_kind,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::MultipleNexuses { .. } => ReplyError {
                 kind: ReplyErrorKind::InvalidArgument,
                 resource: ResourceKind::Unknown,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::LastReplica { .. } => ReplyError {
                 kind: ReplyErrorKind::FailedPrecondition,
                 resource: ResourceKind::Volume,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::LastHealthyReplica { .. } => ReplyError {
                 kind: ReplyErrorKind::FailedPrecondition,
                 resource: ResourceKind::Volume,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::ReplicaCountAchieved { .. } => ReplyError {
                 kind: ReplyErrorKind::ReplicaCountAchieved,
                 resource: ResourceKind::Volume,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::ReplicaChangeCount { .. } => ReplyError {
                 kind: ReplyErrorKind::ReplicaChangeCount,
                 resource: ResourceKind::Volume,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::ReplicaIncrease { .. } => ReplyError {
                 kind: ReplyErrorKind::ReplicaIncrease,
                 resource: ResourceKind::Volume,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::RebuildHistoryNotFound { .. } => ReplyError {
                 kind: ReplyErrorKind::NotFound,
                 resource: ResourceKind::Nexus,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::ReplicaRemovalNoCandidates { .. } => ReplyError {
                 kind: ReplyErrorKind::ReplicaChangeCount,
                 resource: ResourceKind::Volume,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::NoOnlineReplicas { .. } => ReplyError {
                 kind: ReplyErrorKind::VolumeNoReplicas,
                 resource: ResourceKind::Volume,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::NoHealthyReplicas { .. } => ReplyError {
                 kind: ReplyErrorKind::VolumeNoReplicas,
                 resource: ResourceKind::Volume,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::NoSnapshotPools { .. } => ReplyError {
                 kind: ReplyErrorKind::FailedPrecondition,
                 resource: ResourceKind::VolumeSnapshotClone,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::ReplicaCreateNumber { .. } => ReplyError {
                 kind: ReplyErrorKind::ReplicaCreateNumber,
                 resource: ResourceKind::Volume,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::InvalidUuid { ref kind, .. } => ReplyError {
                 kind: ReplyErrorKind::InvalidArgument,
                 resource: kind.clone(),
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::MaxRebuilds { .. } => ReplyError {
                 kind: ReplyErrorKind::ResourceExhausted,
                 resource: ResourceKind::Volume,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
+            },
+            SvcError::RebuildQueued { .. } => ReplyError {
+                kind: ReplyErrorKind::RebuildQueued,
+                resource: ResourceKind::Nexus,
+                source,
+                extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::InvalidApiVersion { .. } => ReplyError {
                 kind: ReplyErrorKind::InvalidArgument,
                 resource: ResourceKind::Unknown,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::SubsystemNotFound { .. } => ReplyError {
                 kind: ReplyErrorKind::NotFound,
                 resource: ResourceKind::NvmeSubsystem,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::UnexpectedSubsystemNqn { .. } => ReplyError {
                 kind: ReplyErrorKind::InvalidArgument,
                 resource: ResourceKind::NvmeSubsystem,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::NvmeParseError { .. } => ReplyError {
                 kind: ReplyErrorKind::Internal,
                 resource: ResourceKind::NvmePath,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::GrpcUdsConnect { .. } => ReplyError {
                 kind: ReplyErrorKind::Unavailable,
                 resource: ResourceKind::Unknown,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::NvmeConnectError { .. } => ReplyError {
                 kind: ReplyErrorKind::Aborted,
                 resource: ResourceKind::NvmeSubsystem,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::NoCapacityToOnline { .. } => ReplyError {
                 kind: ReplyErrorKind::ResourceExhausted,
                 resource: ResourceKind::Pool,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::ResizeReplError { .. } => ReplyError {
                 kind: ReplyErrorKind::FailedPrecondition,
                 resource: ResourceKind::Replica,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
+            },
+            SvcError::PoolGrow { .. } => ReplyError {
+                kind: ReplyErrorKind::FailedPrecondition,
+                resource: ResourceKind::Pool,
+                source,
+                extra,
+                code: code.clone(),
+                details: None,
+            },
+            SvcError::PoolGrowUnsupported { .. } => ReplyError {
+                kind: ReplyErrorKind::Unimplemented,
+                resource: ResourceKind::Pool,
+                source,
+                extra,
+                code: code.clone(),
+                details: None,
+            },
+            SvcError::PoolGrowExceedsDevice { .. } => ReplyError {
+                kind: ReplyErrorKind::OutOfRange,
+                resource: ResourceKind::Pool,
+                source,
+                extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::VolumeResizeArgsInvalid { .. } => ReplyError {
                 kind: ReplyErrorKind::InvalidArgument,
                 resource: ResourceKind::Volume,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::Unimplemented { resource, .. } => ReplyError {
                 kind: ReplyErrorKind::Unimplemented,
                 resource,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::AffinityGroupNotFound { .. } => ReplyError {
                 kind: ReplyErrorKind::NotFound,
                 resource: ResourceKind::AffinityGroup,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::RestrictedReplicaCount { resource, .. } => ReplyError {
                 kind: ReplyErrorKind::FailedPrecondition,
                 resource,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::NReplSnapshotNotAllowed {} => ReplyError {
                 kind: ReplyErrorKind::InvalidArgument,
                 resource: ResourceKind::VolumeSnapshot,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::NReplSnapshotCloneCreationNotAllowed {} => ReplyError {
                 kind: ReplyErrorKind::InvalidArgument,
                 resource: ResourceKind::VolumeSnapshotClone,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::ReplicaSnapSkipped { .. } => ReplyError {
                 kind: ReplyErrorKind::Aborted,
                 resource: ResourceKind::VolumeSnapshot,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::ReplicaSnapMiss { .. } => ReplyError {
                 kind: ReplyErrorKind::Aborted,
                 resource: ResourceKind::VolumeSnapshot,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::ReplicaSnapError { .. } => ReplyError {
                 kind: ReplyErrorKind::Aborted,
                 resource: ResourceKind::VolumeSnapshot,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::VolSnapshotNotFound { .. } => ReplyError {
                 kind: ReplyErrorKind::NotFound,
                 resource: ResourceKind::VolumeSnapshot,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::InvalidSnapshotSource { .. } => ReplyError {
                 kind: ReplyErrorKind::InvalidArgument,
                 resource: ResourceKind::VolumeSnapshot,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::SnapshotNotCreatedNoVolume { .. } => ReplyError {
                 kind: ReplyErrorKind::FailedPrecondition,
                 resource: ResourceKind::VolumeSnapshot,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::ServiceBusy {} => ReplyError {
                 kind: ReplyErrorKind::Aborted,
                 resource: ResourceKind::Unknown,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
+            },
+            SvcError::ResourceLocked { resource_kind, .. } => ReplyError {
+                kind: ReplyErrorKind::ResourceLocked,
+                resource: resource_kind,
+                source,
+                extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::ServiceShutdown {} => ReplyError {
                 kind: ReplyErrorKind::Unavailable,
                 resource: ResourceKind::Unknown,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::SnapshotMaxTransactions { .. } => ReplyError {
                 kind: ReplyErrorKind::DeadlineExceeded,
                 resource: ResourceKind::VolumeSnapshot,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::ClonedSnapshotVolumeRepl {} => ReplyError {
                 kind: ReplyErrorKind::InvalidArgument,
                 resource: ResourceKind::VolumeSnapshotClone,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::ClonedSnapshotVolumeSize {} => ReplyError {
                 kind: ReplyErrorKind::OutOfRange,
                 resource: ResourceKind::VolumeSnapshotClone,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::ClonedSnapshotVolumeThin {} => ReplyError {
                 kind: ReplyErrorKind::InvalidArgument,
                 resource: ResourceKind::VolumeSnapshotClone,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::SnapshotNotCreated {} => ReplyError {
                 kind: ReplyErrorKind::InvalidArgument,
                 resource: ResourceKind::VolumeSnapshot,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::DrainNotAllowedWhenHAisDisabled {} => ReplyError {
                 kind: ReplyErrorKind::FailedPrecondition,
                 resource: ResourceKind::Node,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
             SvcError::SwitchoverNotAllowedWhenHAisDisabled {} => ReplyError {
                 kind: ReplyErrorKind::FailedPrecondition,
                 resource: ResourceKind::Nexus,
                 source,
                 extra,
+                code: code.clone(),
+                details: None,
             },
-        }
+        };
+
+        metrics::record(error_kind.as_ref(), &reply);
+        reply
     }
 }
 
@@ -1008,17 +1799,36 @@ fn grpc_to_reply_error(error: SvcError) -> ReplyError {
                 Code::Unauthenticated => ReplyErrorKind::Unauthenticated,
             };
             let extra = format!("{request}::{source}");
+            let code = SvcErrorDiscriminants::GrpcRequestError.as_ref().to_string();
+            let retry_info = matches!(source.code(), Code::Unavailable | Code::ResourceExhausted)
+                .then_some(RetryInfo {
+                    retry_after: DEFAULT_RETRY_AFTER,
+                });
+            let details = Some(ErrorDetails {
+                retry_info,
+                error_info: Some(ErrorInfo {
+                    reason: code.clone(),
+                    metadata: vec![("request".to_string(), request)],
+                }),
+                quota_failure: None,
+            });
             ReplyError {
                 kind,
                 resource,
                 source: "SvcError::GrpcRequestError".to_string(),
                 extra,
+                code,
+                details,
             }
         }
         _ => unreachable!("Expected a GrpcRequestError!"),
     }
 }
 
+/// Default backoff hint attached to [`ErrorDetails::retry_info`] for `Unavailable`/
+/// `ResourceExhausted` gRPC statuses that don't carry their own retry delay.
+const DEFAULT_RETRY_AFTER: std::time::Duration = std::time::Duration::from_secs(1);
+
 /// Not enough resources available
 #[derive(Debug, Snafu)]
 #[allow(missing_docs)]
@@ -1031,6 +1841,75 @@ pub enum NotEnough {
     OfNexuses { have: u64, need: u64 },
     #[snafu(display("Not enough nodes available, {}/{}", have, need))]
     OfNodes { have: u64, need: u64 },
-    #[snafu(display("Not enough free space in the pool"))]
-    PoolFree {},
+    #[snafu(display("Not enough free space in the pool, {}/{} bytes", have, need))]
+    PoolFree { have: u64, need: u64 },
+    #[snafu(display(
+        "Not enough pool capacity to place {}/{} replicas ({} available after costing)",
+        have,
+        need,
+        available
+    ))]
+    ReplicaCapacity {
+        have: u64,
+        need: u64,
+        available: u64,
+    },
+    #[snafu(display(
+        "Not enough failure domains to spread {}/{} replicas, only {} available",
+        have,
+        need,
+        domains
+    ))]
+    ReplicaSpread {
+        have: u64,
+        need: u64,
+        domains: u64,
+    },
+}
+
+/// Error-rate metrics emitted from the `SvcError` -> `ReplyError` conversion, the single choke
+/// point every service failure passes through. Registered on the default `prometheus` registry,
+/// so they're picked up by whatever exporter a binary already serves (see eg
+/// `control_plane_grpc::operations::pool::metrics::encode`), without this module needing to own
+/// an HTTP endpoint of its own.
+mod metrics {
+    use super::ReplyError;
+    use once_cell::sync::Lazy;
+    use prometheus::{register_histogram_vec, register_int_counter_vec, HistogramVec, IntCounterVec};
+
+    /// Total `SvcError` occurrences, labelled by the originating variant, the affected resource
+    /// and the `ReplyErrorKind` it was mapped to. Lets operators alert on spikes of eg
+    /// `ResourceExhausted` or `FailedPersist` per pool/volume.
+    static SVC_ERRORS_TOTAL: Lazy<IntCounterVec> = Lazy::new(|| {
+        register_int_counter_vec!(
+            "svc_errors_total",
+            "Total SvcError -> ReplyError conversions by variant, resource and ReplyErrorKind",
+            &["error_kind", "resource", "reply_kind"]
+        )
+        .expect("metric can be registered")
+    });
+
+    /// Distribution of conversions per resource kind, so a resource with an unusually large share
+    /// of the cluster's errors stands out even without per-variant granularity.
+    static SVC_ERRORS_BY_RESOURCE: Lazy<HistogramVec> = Lazy::new(|| {
+        register_histogram_vec!(
+            "svc_errors_by_resource",
+            "Count of SvcError -> ReplyError conversions observed per resource kind",
+            &["resource"]
+        )
+        .expect("metric can be registered")
+    });
+
+    /// Record one `SvcError` -> `ReplyError` conversion. `error_kind` is the originating
+    /// variant's name, obtained from `SvcErrorDiscriminants` rather than a giant match.
+    pub(super) fn record(error_kind: &str, reply: &ReplyError) {
+        let resource = reply.resource.to_string();
+        let reply_kind = format!("{:?}", reply.kind);
+        SVC_ERRORS_TOTAL
+            .with_label_values(&[error_kind, &resource, &reply_kind])
+            .inc();
+        SVC_ERRORS_BY_RESOURCE
+            .with_label_values(&[&resource])
+            .observe(1.0);
+    }
 }
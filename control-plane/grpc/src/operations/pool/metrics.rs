@@ -0,0 +1,216 @@
+use crate::operations::pool::traits::PoolOperations;
+use once_cell::sync::Lazy;
+use prometheus::{
+    register_histogram_vec, register_int_counter_vec, register_int_gauge_vec, HistogramVec,
+    IntCounterVec, IntGaugeVec, TextEncoder,
+};
+use std::{sync::Arc, time::Duration};
+use stor_port::types::v0::transport::{Filter, PoolStatus};
+use tracing::error;
+
+/// Per-pool capacity/commitment gauges, refreshed from a periodic `get_pools` poll.
+static POOL_CAPACITY_BYTES: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "pool_capacity_bytes",
+        "Pool capacity in bytes",
+        &["pool", "node"]
+    )
+    .expect("metric can be registered")
+});
+static POOL_USED_BYTES: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!("pool_used_bytes", "Pool used bytes", &["pool", "node"])
+        .expect("metric can be registered")
+});
+static POOL_COMMITTED_BYTES: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "pool_committed_bytes",
+        "Pool committed (replica accrued) bytes",
+        &["pool", "node"]
+    )
+    .expect("metric can be registered")
+});
+static POOL_FREE_BYTES: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!("pool_free_bytes", "Pool free bytes", &["pool", "node"])
+        .expect("metric can be registered")
+});
+static POOL_OVERCOMMIT_RATIO: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "pool_thin_overcommit_percent",
+        "Thin-provisioning overcommit ratio (committed/capacity) as a percentage",
+        &["pool", "node"]
+    )
+    .expect("metric can be registered")
+});
+static POOL_STATUS: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "pool_status",
+        "Pool status, one gauge per (pool, status) pair set to 1 for the active status",
+        &["pool", "node", "status"]
+    )
+    .expect("metric can be registered")
+});
+
+/// Call counters/latency for the pool gRPC operations, labelled by outcome.
+static POOL_CALLS_TOTAL: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "pool_grpc_calls_total",
+        "Total number of pool gRPC calls",
+        &["call", "outcome"]
+    )
+    .expect("metric can be registered")
+});
+static POOL_CALL_ERRORS_TOTAL: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "pool_grpc_call_errors_total",
+        "Total number of pool gRPC call errors by ReplyErrorKind",
+        &["call", "error_kind"]
+    )
+    .expect("metric can be registered")
+});
+static POOL_CALL_LATENCY: Lazy<HistogramVec> = Lazy::new(|| {
+    register_histogram_vec!(
+        "pool_grpc_call_latency_seconds",
+        "Latency of pool gRPC calls",
+        &["call"]
+    )
+    .expect("metric can be registered")
+});
+
+/// Names of the pool gRPC calls instrumented by [`CallTimer`].
+pub const CALL_CREATE_POOL: &str = "create_pool";
+pub const CALL_DESTROY_POOL: &str = "destroy_pool";
+pub const CALL_LABEL_POOL: &str = "label_pool";
+pub const CALL_GET_POOLS: &str = "get_pools";
+
+/// RAII timer that records call count, latency and (on `record_error`) the error outcome for a
+/// single pool gRPC call.
+pub struct CallTimer {
+    call: &'static str,
+    start: std::time::Instant,
+}
+
+impl CallTimer {
+    /// Start timing the named call.
+    pub fn start(call: &'static str) -> Self {
+        Self {
+            call,
+            start: std::time::Instant::now(),
+        }
+    }
+    /// Record that the call failed with the given `ReplyErrorKind`.
+    pub fn record_error(self, error_kind: &str) {
+        POOL_CALLS_TOTAL.with_label_values(&[self.call, "error"]).inc();
+        POOL_CALL_ERRORS_TOTAL
+            .with_label_values(&[self.call, error_kind])
+            .inc();
+        self.observe();
+    }
+    /// Record that the call succeeded.
+    pub fn record_ok(self) {
+        POOL_CALLS_TOTAL.with_label_values(&[self.call, "ok"]).inc();
+        self.observe();
+    }
+    fn observe(self) {
+        POOL_CALL_LATENCY
+            .with_label_values(&[self.call])
+            .observe(self.start.elapsed().as_secs_f64());
+    }
+}
+
+/// Encode all registered metrics in the Prometheus text exposition format.
+pub fn encode() -> String {
+    let metric_families = prometheus::gather();
+    let mut buffer = String::new();
+    if let Err(error) = TextEncoder::new().encode_utf8(&metric_families, &mut buffer) {
+        error!(%error, "Failed to encode pool metrics");
+    }
+    buffer
+}
+
+/// Serve the pool metrics on a `/metrics` HTTP endpoint at the given address.
+pub async fn serve(addr: std::net::SocketAddr) {
+    use hyper::{
+        server::conn::http1,
+        service::service_fn,
+        Request, Response,
+    };
+    use hyper_util::rt::TokioIo;
+    use tokio::net::TcpListener;
+
+    let listener = match TcpListener::bind(addr).await {
+        Ok(listener) => listener,
+        Err(error) => {
+            error!(%error, %addr, "Failed to bind pool metrics listener");
+            return;
+        }
+    };
+    loop {
+        let (stream, _) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(error) => {
+                error!(%error, "Failed to accept pool metrics connection");
+                continue;
+            }
+        };
+        tokio::task::spawn(async move {
+            let io = TokioIo::new(stream);
+            let service = service_fn(|_req: Request<hyper::body::Incoming>| async move {
+                Ok::<_, std::convert::Infallible>(Response::new(encode()))
+            });
+            if let Err(error) = http1::Builder::new().serve_connection(io, service).await {
+                error!(%error, "Pool metrics connection error");
+            }
+        });
+    }
+}
+
+/// Periodically refresh the per-pool gauges from a `get_pools` poll of the given `service`.
+pub async fn refresh_pool_gauges_periodically(
+    service: Arc<dyn PoolOperations>,
+    period: Duration,
+) {
+    let mut interval = tokio::time::interval(period);
+    loop {
+        interval.tick().await;
+        match service.get(Filter::None, None).await {
+            Ok(pools) => {
+                for pool in pools.into_inner() {
+                    let Some(state) = pool.state() else { continue };
+                    let labels: [&str; 2] = [pool.id().as_str(), state.node.as_str()];
+                    POOL_CAPACITY_BYTES
+                        .with_label_values(&labels)
+                        .set(state.capacity as i64);
+                    POOL_USED_BYTES.with_label_values(&labels).set(state.used as i64);
+                    let committed = state.committed.unwrap_or(0);
+                    POOL_COMMITTED_BYTES
+                        .with_label_values(&labels)
+                        .set(committed as i64);
+                    let free = state.capacity.saturating_sub(state.used);
+                    POOL_FREE_BYTES.with_label_values(&labels).set(free as i64);
+                    let overcommit_pct = if state.capacity == 0 {
+                        0
+                    } else {
+                        (committed * 100 / state.capacity) as i64
+                    };
+                    POOL_OVERCOMMIT_RATIO
+                        .with_label_values(&labels)
+                        .set(overcommit_pct);
+                    for status in [
+                        PoolStatus::Unknown,
+                        PoolStatus::Online,
+                        PoolStatus::Degraded,
+                        PoolStatus::Faulted,
+                        PoolStatus::Stopped,
+                        PoolStatus::Locked,
+                    ] {
+                        let active = (status == state.status) as i64;
+                        POOL_STATUS
+                            .with_label_values(&[pool.id().as_str(), state.node.as_str(), &status.to_string()])
+                            .set(active);
+                    }
+                }
+            }
+            Err(error) => error!(%error, "Failed to refresh pool metrics"),
+        }
+    }
+}
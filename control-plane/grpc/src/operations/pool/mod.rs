@@ -0,0 +1,8 @@
+/// Prometheus metrics for the pool gRPC operations.
+pub mod metrics;
+/// Loki-backed log queries scoped to a pool/node and time range.
+pub mod logs;
+/// Pool gRPC server.
+pub mod server;
+/// Pool operation traits, implemented by the core agent's `Service`.
+pub mod traits;
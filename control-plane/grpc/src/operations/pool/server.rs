@@ -1,5 +1,8 @@
 use crate::{
-    operations::pool::traits::PoolOperations,
+    operations::pool::{
+        metrics::{self, CallTimer},
+        traits::{error_kind_label, PoolOperations},
+    },
     pool,
     pool::{
         create_pool_reply, get_pools_reply, label_pool_reply,
@@ -39,13 +42,20 @@ impl PoolGrpc for PoolServer {
         request: Request<CreatePoolRequest>,
     ) -> Result<tonic::Response<pool::CreatePoolReply>, tonic::Status> {
         let req: CreatePoolRequest = request.into_inner();
+        let timer = CallTimer::start(metrics::CALL_CREATE_POOL);
         match self.service.create(&req, None).await {
-            Ok(pool) => Ok(Response::new(CreatePoolReply {
-                reply: Some(create_pool_reply::Reply::Pool(pool.into())),
-            })),
-            Err(err) => Ok(Response::new(CreatePoolReply {
-                reply: Some(create_pool_reply::Reply::Error(err.into())),
-            })),
+            Ok(pool) => {
+                timer.record_ok();
+                Ok(Response::new(CreatePoolReply {
+                    reply: Some(create_pool_reply::Reply::Pool(pool.into())),
+                }))
+            }
+            Err(err) => {
+                timer.record_error(error_kind_label(&err.kind));
+                Ok(Response::new(CreatePoolReply {
+                    reply: Some(create_pool_reply::Reply::Error(err.into())),
+                }))
+            }
         }
     }
 
@@ -54,11 +64,18 @@ impl PoolGrpc for PoolServer {
         request: Request<DestroyPoolRequest>,
     ) -> Result<tonic::Response<DestroyPoolReply>, tonic::Status> {
         let req = request.into_inner();
+        let timer = CallTimer::start(metrics::CALL_DESTROY_POOL);
         match self.service.destroy(&req, None).await {
-            Ok(()) => Ok(Response::new(DestroyPoolReply { error: None })),
-            Err(e) => Ok(Response::new(DestroyPoolReply {
-                error: Some(e.into()),
-            })),
+            Ok(()) => {
+                timer.record_ok();
+                Ok(Response::new(DestroyPoolReply { error: None }))
+            }
+            Err(e) => {
+                timer.record_error(error_kind_label(&e.kind));
+                Ok(Response::new(DestroyPoolReply {
+                    error: Some(e.into()),
+                }))
+            }
         }
     }
 
@@ -67,26 +84,34 @@ impl PoolGrpc for PoolServer {
         request: Request<GetPoolsRequest>,
     ) -> Result<tonic::Response<pool::GetPoolsReply>, tonic::Status> {
         let req: GetPoolsRequest = request.into_inner();
+        let timer = CallTimer::start(metrics::CALL_GET_POOLS);
 
         let filter = match req.filter {
             Some(filter) => match Filter::try_from(filter) {
                 Ok(filter) => filter,
                 Err(err) => {
+                    timer.record_error(error_kind_label(&err.kind));
                     return Ok(Response::new(GetPoolsReply {
                         reply: Some(get_pools_reply::Reply::Error(err.into())),
-                    }))
+                    }));
                 }
             },
             None => Filter::None,
         };
 
         match self.service.get(filter, None).await {
-            Ok(pools) => Ok(Response::new(GetPoolsReply {
-                reply: Some(get_pools_reply::Reply::Pools(pools.into())),
-            })),
-            Err(err) => Ok(Response::new(GetPoolsReply {
-                reply: Some(get_pools_reply::Reply::Error(err.into())),
-            })),
+            Ok(pools) => {
+                timer.record_ok();
+                Ok(Response::new(GetPoolsReply {
+                    reply: Some(get_pools_reply::Reply::Pools(pools.into())),
+                }))
+            }
+            Err(err) => {
+                timer.record_error(error_kind_label(&err.kind));
+                Ok(Response::new(GetPoolsReply {
+                    reply: Some(get_pools_reply::Reply::Error(err.into())),
+                }))
+            }
         }
     }
 
@@ -95,13 +120,20 @@ impl PoolGrpc for PoolServer {
         request: tonic::Request<LabelPoolRequest>,
     ) -> Result<tonic::Response<LabelPoolReply>, tonic::Status> {
         let req: LabelPoolRequest = request.into_inner();
+        let timer = CallTimer::start(metrics::CALL_LABEL_POOL);
         match self.service.label(&req, None).await {
-            Ok(pool) => Ok(Response::new(LabelPoolReply {
-                reply: Some(label_pool_reply::Reply::Pool(pool.into())),
-            })),
-            Err(err) => Ok(Response::new(LabelPoolReply {
-                reply: Some(label_pool_reply::Reply::Error(err.into())),
-            })),
+            Ok(pool) => {
+                timer.record_ok();
+                Ok(Response::new(LabelPoolReply {
+                    reply: Some(label_pool_reply::Reply::Pool(pool.into())),
+                }))
+            }
+            Err(err) => {
+                timer.record_error(error_kind_label(&err.kind));
+                Ok(Response::new(LabelPoolReply {
+                    reply: Some(label_pool_reply::Reply::Error(err.into())),
+                }))
+            }
         }
     }
 
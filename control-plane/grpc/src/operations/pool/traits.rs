@@ -0,0 +1,379 @@
+use crate::{
+    context::Context,
+    pool::{
+        CreatePoolRequest, DestroyPoolRequest, LabelPoolRequest, StartPoolRequest,
+        StopPoolRequest, UnlabelPoolRequest,
+    },
+};
+use stor_port::{
+    transport_api::{v0::Pools, ReplyError, ReplyErrorKind},
+    types::v0::transport::{
+        CreatePool, DestroyPool, Filter, LabelPool, NodeId, Pool, PoolEncryption, PoolId,
+        PoolUuid, StartPool, StopPool, UnlabelPool, UnlockMethod,
+    },
+};
+
+/// Information needed to create a pool, decoupled from the wire representation (gRPC request or
+/// internal transport type) that carries it.
+pub trait CreatePoolInfo: Send + Sync {
+    /// Id of the io-engine instance the pool should be created on.
+    fn node(&self) -> NodeId;
+    /// Id of the pool to create.
+    fn id(&self) -> PoolId;
+    /// Disk device paths or URIs to be claimed by the pool.
+    fn disks(&self) -> Vec<String>;
+    /// Labels to be set on the pool.
+    fn labels(&self) -> Option<std::collections::HashMap<String, String>>;
+    /// Encrypt the pool's devices at rest with the given cipher and key, if set.
+    fn encryption(&self) -> Option<PoolEncryption>;
+}
+
+/// Information needed to edit (re-create/import) a pool.
+pub trait EditPoolInfo: Send + Sync {
+    /// Id of the io-engine instance the pool lives on.
+    fn node(&self) -> NodeId;
+    /// Id of the pool to edit.
+    fn id(&self) -> PoolId;
+    /// Disk device paths or URIs to be claimed by the pool.
+    fn disks(&self) -> Vec<String>;
+    /// Labels to be set on the pool.
+    fn labels(&self) -> Option<std::collections::HashMap<String, String>>;
+    /// Encrypt the pool's devices at rest with the given cipher and key, if set.
+    fn encryption(&self) -> Option<PoolEncryption>;
+}
+
+/// Information needed to destroy a pool.
+pub trait DestroyPoolInfo: Send + Sync {
+    /// Id of the io-engine instance the pool lives on.
+    fn node(&self) -> NodeId;
+    /// Id of the pool to destroy.
+    fn id(&self) -> PoolId;
+}
+
+/// Information needed to start (import) a stopped pool.
+pub trait StartPoolInfo: Send + Sync {
+    /// Id of the io-engine instance the pool lives on.
+    fn node(&self) -> NodeId;
+    /// Id of the pool to start.
+    fn id(&self) -> PoolId;
+    /// The pool uuid, if known.
+    fn uuid(&self) -> Option<PoolUuid>;
+    /// How to unlock the pool's devices, if they're encrypted.
+    fn unlock_method(&self) -> Option<UnlockMethod>;
+}
+
+/// Information needed to stop a pool.
+pub trait StopPoolInfo: Send + Sync {
+    /// Id of the io-engine instance the pool lives on.
+    fn node(&self) -> NodeId;
+    /// Id of the pool to stop.
+    fn id(&self) -> PoolId;
+}
+
+/// Information needed to label a pool.
+pub trait LabelPoolInfo: Send + Sync {
+    /// Id of the pool to label.
+    fn id(&self) -> PoolId;
+    /// Labels to set on the pool.
+    fn labels(&self) -> std::collections::HashMap<String, String>;
+    /// Whether to overwrite an existing label with the same key.
+    fn overwrite(&self) -> bool;
+}
+
+/// Information needed to remove a label from a pool.
+pub trait UnlabelPoolInfo: Send + Sync {
+    /// Id of the pool to unlabel.
+    fn id(&self) -> PoolId;
+    /// Key of the label to remove.
+    fn label_key(&self) -> String;
+}
+
+impl From<&dyn CreatePoolInfo> for CreatePool {
+    fn from(info: &dyn CreatePoolInfo) -> Self {
+        Self {
+            node: info.node(),
+            id: info.id(),
+            disks: info.disks().into_iter().map(From::from).collect(),
+            labels: info.labels(),
+            encryption: info.encryption(),
+        }
+    }
+}
+impl From<&dyn EditPoolInfo> for CreatePool {
+    fn from(info: &dyn EditPoolInfo) -> Self {
+        Self {
+            node: info.node(),
+            id: info.id(),
+            disks: info.disks().into_iter().map(From::from).collect(),
+            labels: info.labels(),
+            encryption: info.encryption(),
+        }
+    }
+}
+impl From<&dyn DestroyPoolInfo> for DestroyPool {
+    fn from(info: &dyn DestroyPoolInfo) -> Self {
+        Self {
+            node: info.node(),
+            id: info.id(),
+        }
+    }
+}
+impl From<&dyn StartPoolInfo> for StartPool {
+    fn from(info: &dyn StartPoolInfo) -> Self {
+        Self {
+            node: info.node(),
+            id: info.id(),
+            uuid: info.uuid(),
+            unlock_method: info.unlock_method(),
+        }
+    }
+}
+impl From<&dyn StopPoolInfo> for StopPool {
+    fn from(info: &dyn StopPoolInfo) -> Self {
+        Self {
+            node: info.node(),
+            id: info.id(),
+        }
+    }
+}
+impl From<&dyn LabelPoolInfo> for LabelPool {
+    fn from(info: &dyn LabelPoolInfo) -> Self {
+        Self {
+            id: info.id(),
+            labels: info.labels(),
+            overwrite: info.overwrite(),
+        }
+    }
+}
+impl From<&dyn UnlabelPoolInfo> for UnlabelPool {
+    fn from(info: &dyn UnlabelPoolInfo) -> Self {
+        Self {
+            id: info.id(),
+            label_key: info.label_key(),
+        }
+    }
+}
+
+impl CreatePoolInfo for CreatePoolRequest {
+    fn node(&self) -> NodeId {
+        self.node_id.clone().into()
+    }
+    fn id(&self) -> PoolId {
+        self.pool_id.clone().into()
+    }
+    fn disks(&self) -> Vec<String> {
+        self.disks.clone()
+    }
+    fn labels(&self) -> Option<std::collections::HashMap<String, String>> {
+        self.labels.clone()
+    }
+    fn encryption(&self) -> Option<PoolEncryption> {
+        self.encryption.clone().map(From::from)
+    }
+}
+impl DestroyPoolInfo for DestroyPoolRequest {
+    fn node(&self) -> NodeId {
+        self.node_id.clone().into()
+    }
+    fn id(&self) -> PoolId {
+        self.pool_id.clone().into()
+    }
+}
+impl StartPoolInfo for StartPoolRequest {
+    fn node(&self) -> NodeId {
+        self.node_id.clone().into()
+    }
+    fn id(&self) -> PoolId {
+        self.pool_id.clone().into()
+    }
+    fn uuid(&self) -> Option<PoolUuid> {
+        self.pool_uuid.clone().map(From::from)
+    }
+    fn unlock_method(&self) -> Option<UnlockMethod> {
+        self.unlock_method.clone().map(From::from)
+    }
+}
+impl StopPoolInfo for StopPoolRequest {
+    fn node(&self) -> NodeId {
+        self.node_id.clone().into()
+    }
+    fn id(&self) -> PoolId {
+        self.pool_id.clone().into()
+    }
+}
+impl LabelPoolInfo for LabelPoolRequest {
+    fn id(&self) -> PoolId {
+        self.pool_id.clone().into()
+    }
+    fn labels(&self) -> std::collections::HashMap<String, String> {
+        self.labels.clone()
+    }
+    fn overwrite(&self) -> bool {
+        self.overwrite
+    }
+}
+impl UnlabelPoolInfo for UnlabelPoolRequest {
+    fn id(&self) -> PoolId {
+        self.pool_id.clone().into()
+    }
+    fn label_key(&self) -> String {
+        self.label_key.clone()
+    }
+}
+
+/// Machine-readable category for a pool operation failure, as opposed to a transport/connectivity
+/// failure. Lets a caller decide whether retrying the operation as-is could ever succeed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReasonCode {
+    /// The targeted pool (or its node) doesn't exist.
+    NotFound,
+    /// The pool, or a resource it depends on, already exists.
+    AlreadyExists,
+    /// The node/pool doesn't have enough free capacity to satisfy the request.
+    CapacityExceeded,
+    /// The request's topology constraints can't be satisfied by any candidate pool.
+    TopologyMismatch,
+    /// The operation failed for a reason intrinsic to the pool's state (faulted, conflicting
+    /// in-flight operation, etc.) that retrying the same request won't fix.
+    Faulted,
+}
+
+/// A pool operation's two-layer error: either the request never reached (or never got a reply
+/// from) the targeted agent, in which case retrying the same request may succeed once
+/// connectivity recovers, or the agent processed it and rejected it for a business reason that
+/// retrying as-is won't change.
+#[derive(Debug, Clone)]
+pub enum PoolOperationError {
+    /// Transport/connectivity failure: channel down, deadline exceeded, etc. Safe to retry.
+    Transport(ReplyError),
+    /// The operation was processed and rejected for the given, machine-readable reason.
+    Operation {
+        /// Machine-readable reason code.
+        reason: ReasonCode,
+        /// The full error, for logging/diagnostics.
+        error: ReplyError,
+    },
+}
+
+impl PoolOperationError {
+    /// Classify a raw [`ReplyError`] into the transport/operation two-layer error, based on its
+    /// [`ReplyErrorKind`].
+    pub fn classify(error: ReplyError) -> Self {
+        match error.kind {
+            ReplyErrorKind::Unavailable | ReplyErrorKind::DeadlineExceeded => {
+                Self::Transport(error)
+            }
+            ReplyErrorKind::NotFound => Self::Operation {
+                reason: ReasonCode::NotFound,
+                error,
+            },
+            ReplyErrorKind::AlreadyExists => Self::Operation {
+                reason: ReasonCode::AlreadyExists,
+                error,
+            },
+            ReplyErrorKind::ResourceExhausted => Self::Operation {
+                reason: ReasonCode::CapacityExceeded,
+                error,
+            },
+            ReplyErrorKind::FailedPrecondition => Self::Operation {
+                reason: ReasonCode::TopologyMismatch,
+                error,
+            },
+            _ => Self::Operation {
+                reason: ReasonCode::Faulted,
+                error,
+            },
+        }
+    }
+
+    /// Whether retrying the same request might succeed, i.e. whether this is a transport error.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::Transport(_))
+    }
+
+    /// The machine-readable reason code, if this is a typed operation failure.
+    pub fn reason(&self) -> Option<ReasonCode> {
+        match self {
+            Self::Transport(_) => None,
+            Self::Operation { reason, .. } => Some(*reason),
+        }
+    }
+
+    /// The underlying [`ReplyError`], regardless of which layer it came from.
+    pub fn into_reply_error(self) -> ReplyError {
+        match self {
+            Self::Transport(error) => error,
+            Self::Operation { error, .. } => error,
+        }
+    }
+}
+
+impl From<ReplyError> for PoolOperationError {
+    fn from(error: ReplyError) -> Self {
+        Self::classify(error)
+    }
+}
+
+/// A low-cardinality label for `kind`, suitable for a metrics dimension: `"transport"` for a
+/// connectivity failure, otherwise the operation's [`ReasonCode`].
+pub fn error_kind_label(kind: &ReplyErrorKind) -> &'static str {
+    match kind {
+        ReplyErrorKind::Unavailable | ReplyErrorKind::DeadlineExceeded => "transport",
+        ReplyErrorKind::NotFound => "not_found",
+        ReplyErrorKind::AlreadyExists => "already_exists",
+        ReplyErrorKind::ResourceExhausted => "capacity_exceeded",
+        ReplyErrorKind::FailedPrecondition => "topology_mismatch",
+        _ => "faulted",
+    }
+}
+
+/// Pool operations, implemented by the core agent's `Service` and consumed by the gRPC server and
+/// the REST handlers alike.
+#[tonic::async_trait]
+pub trait PoolOperations: Send + Sync {
+    /// Create a pool.
+    async fn create(
+        &self,
+        pool: &dyn CreatePoolInfo,
+        ctx: Option<Context>,
+    ) -> Result<Pool, ReplyError>;
+    /// Create (or re-import) a pool from the given parameters, without failing if it exists.
+    async fn patch(
+        &self,
+        pool: &dyn EditPoolInfo,
+        ctx: Option<Context>,
+    ) -> Result<Pool, ReplyError>;
+    /// Destroy a pool.
+    async fn destroy(
+        &self,
+        pool: &dyn DestroyPoolInfo,
+        ctx: Option<Context>,
+    ) -> Result<(), ReplyError>;
+    /// Start (import) a stopped pool.
+    async fn start(
+        &self,
+        pool: &dyn StartPoolInfo,
+        ctx: Option<Context>,
+    ) -> Result<Pool, ReplyError>;
+    /// Stop a pool, taking it offline without destroying its on-disk data. It remains down
+    /// across io-engine restarts until explicitly started again.
+    async fn stop(
+        &self,
+        pool: &dyn StopPoolInfo,
+        ctx: Option<Context>,
+    ) -> Result<Pool, ReplyError>;
+    /// Get pools matching the given filter.
+    async fn get(&self, filter: Filter, ctx: Option<Context>) -> Result<Pools, ReplyError>;
+    /// Label a pool.
+    async fn label(
+        &self,
+        pool: &dyn LabelPoolInfo,
+        ctx: Option<Context>,
+    ) -> Result<Pool, ReplyError>;
+    /// Remove a label from a pool.
+    async fn unlabel(
+        &self,
+        pool: &dyn UnlabelPoolInfo,
+        ctx: Option<Context>,
+    ) -> Result<Pool, ReplyError>;
+}
@@ -0,0 +1,2 @@
+/// Replica snapshot operation traits, implemented by the core agent's `Service`.
+pub mod traits;
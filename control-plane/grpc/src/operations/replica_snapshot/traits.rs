@@ -0,0 +1,73 @@
+use crate::context::Context;
+use stor_port::{
+    transport_api::ReplyError,
+    types::v0::transport::{
+        CreateReplicaSnapshot, DestroyReplicaSnapshot, Filter, NodeId, PoolId, ReplicaId,
+        ReplicaSnapshot, SnapshotId,
+    },
+};
+
+/// Information needed to create a snapshot of a replica.
+pub trait CreateReplicaSnapshotInfo: Send + Sync {
+    /// Id of the io-engine instance the replica lives on.
+    fn node(&self) -> NodeId;
+    /// Id of the pool the replica lives on.
+    fn pool(&self) -> PoolId;
+    /// Id of the replica to snapshot.
+    fn replica(&self) -> ReplicaId;
+    /// Id to give the new snapshot.
+    fn snap_uuid(&self) -> SnapshotId;
+}
+
+/// Information needed to destroy a replica snapshot.
+pub trait DestroyReplicaSnapshotInfo: Send + Sync {
+    /// Id of the io-engine instance the snapshot lives on.
+    fn node(&self) -> NodeId;
+    /// Id of the snapshot to destroy.
+    fn uuid(&self) -> SnapshotId;
+}
+
+impl From<&dyn CreateReplicaSnapshotInfo> for CreateReplicaSnapshot {
+    fn from(info: &dyn CreateReplicaSnapshotInfo) -> Self {
+        Self {
+            node: info.node(),
+            pool: info.pool(),
+            replica: info.replica(),
+            snap_uuid: info.snap_uuid(),
+        }
+    }
+}
+impl From<&dyn DestroyReplicaSnapshotInfo> for DestroyReplicaSnapshot {
+    fn from(info: &dyn DestroyReplicaSnapshotInfo) -> Self {
+        Self {
+            node: info.node(),
+            uuid: info.uuid(),
+        }
+    }
+}
+
+/// Replica snapshot operations, implemented by the core agent's `Service` and consumed by the
+/// gRPC server and the REST handlers alike, following the same create/destroy/list shape as
+/// `ReplicaOperations` and `PoolOperations`.
+#[tonic::async_trait]
+pub trait SnapshotOperations: Send + Sync {
+    /// Create a snapshot of a replica.
+    async fn create_replica_snapshot(
+        &self,
+        snapshot: &dyn CreateReplicaSnapshotInfo,
+        ctx: Option<Context>,
+    ) -> Result<ReplicaSnapshot, ReplyError>;
+    /// List replica snapshots matching the given filter, e.g. `Filter::ReplicaSnapshot` for every
+    /// snapshot of a given replica.
+    async fn list_replica_snapshots(
+        &self,
+        filter: Filter,
+        ctx: Option<Context>,
+    ) -> Result<Vec<ReplicaSnapshot>, ReplyError>;
+    /// Destroy a replica snapshot.
+    async fn destroy_replica_snapshot(
+        &self,
+        snapshot: &dyn DestroyReplicaSnapshotInfo,
+        ctx: Option<Context>,
+    ) -> Result<(), ReplyError>;
+}
@@ -0,0 +1,74 @@
+//! A `Future` combinator that instruments every `poll()` call with wall-clock timing, modeled on
+//! pict-rs's `WithPollTimer`: every mutating pool/replica call in the core agent's pool service is
+//! wrapped in
+//! `Context::spawn(async move { ... }).await??`, but a stalled io-engine RPC against a degraded
+//! node is otherwise invisible until the whole call eventually times out. Wrapping the spawned
+//! future in [`WithPollTimer`] (via [`PollTimerExt::with_poll_timer`]) makes individual slow
+//! polls visible in logs immediately, without needing a tracing span around every call site.
+//!
+//! This is the gRPC-layer counterpart to `k8s::operators::pool::poll_timer::call_with_timer`,
+//! which times whole REST calls from the operator; this one times individual `poll()` calls on
+//! whatever future `Context::spawn` is handed, which is where every mutating `PoolOperations`/
+//! `ReplicaOperations`/`SnapshotOperations` method of the core agent's `Service` routes through.
+
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context as TaskContext, Poll},
+    time::{Duration, Instant},
+};
+use tracing::warn;
+
+/// Default threshold above which a single `poll()` call is considered slow enough to warn about.
+pub const DEFAULT_POLL_WARN_THRESHOLD: Duration = Duration::from_millis(500);
+
+/// Wraps a future, warning whenever a single `poll()` call takes longer than `threshold` to
+/// return. The inner future is boxed so this works uniformly whether or not it is itself `Unpin`
+/// (an `async move { ... }` block, as used at every `Context::spawn` call site, is not).
+pub struct WithPollTimer<F: Future> {
+    operation: &'static str,
+    threshold: Duration,
+    inner: Pin<Box<F>>,
+}
+
+impl<F: Future> WithPollTimer<F> {
+    /// Wrap `inner`, warning on any `poll()` exceeding [`DEFAULT_POLL_WARN_THRESHOLD`].
+    pub fn new(operation: &'static str, inner: F) -> Self {
+        Self::with_threshold(operation, DEFAULT_POLL_WARN_THRESHOLD, inner)
+    }
+
+    /// Wrap `inner`, warning on any `poll()` exceeding the given `threshold`.
+    pub fn with_threshold(operation: &'static str, threshold: Duration, inner: F) -> Self {
+        Self {
+            operation,
+            threshold,
+            inner: Box::pin(inner),
+        }
+    }
+}
+
+impl<F: Future> Future for WithPollTimer<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Self::Output> {
+        let started = Instant::now();
+        let result = self.inner.as_mut().poll(cx);
+        let elapsed = started.elapsed();
+        if elapsed > self.threshold {
+            warn!(operation = self.operation, ?elapsed, "slow poll");
+        }
+        result
+    }
+}
+
+/// Lets a call site write `fut.with_poll_timer("create_pool")` instead of the more verbose
+/// `WithPollTimer::new("create_pool", fut)`, the same way `Context::spawn` is meant to be used:
+/// `Context::spawn(async move { service.create_pool(&req).await }.with_poll_timer("create_pool"))`.
+pub trait PollTimerExt: Future + Sized {
+    /// Instrument this future with [`WithPollTimer`] at the default threshold.
+    fn with_poll_timer(self, operation: &'static str) -> WithPollTimer<Self> {
+        WithPollTimer::new(operation, self)
+    }
+}
+
+impl<F: Future> PollTimerExt for F {}
@@ -0,0 +1,117 @@
+use super::*;
+use grpc::operations::pool::logs::{LogSource, PoolLogLine, PoolLogQuery, TAIL_POLL_PERIOD};
+use k8s_proxy::LokiClient;
+use std::sync::Arc;
+use stor_port::transport_api::{ReplyError, ReplyErrorKind, ResourceKind};
+use tokio::sync::mpsc;
+
+/// Adapts a `k8s_proxy::LokiClient` to the `LogSource` trait the pool log query operations are
+/// built on top of.
+pub struct LokiLogSource(LokiClient);
+
+impl LokiLogSource {
+    /// New source backed by `client`.
+    pub fn new(client: LokiClient) -> Self {
+        Self(client)
+    }
+}
+
+#[tonic::async_trait]
+impl LogSource for LokiLogSource {
+    async fn query_range(
+        &self,
+        selector: &str,
+        start_ns: i64,
+        end_ns: i64,
+        limit: u32,
+    ) -> Result<Vec<PoolLogLine>, ReplyError> {
+        self.0
+            .query_range(selector, start_ns, end_ns, limit)
+            .await
+            .map(to_pool_lines)
+            .map_err(loki_error)
+    }
+
+    async fn tail_once(
+        &self,
+        selector: &str,
+        since_ns: i64,
+    ) -> Result<Vec<PoolLogLine>, ReplyError> {
+        self.0
+            .tail_once(selector, since_ns)
+            .await
+            .map(to_pool_lines)
+            .map_err(loki_error)
+    }
+}
+
+fn to_pool_lines(entries: Vec<k8s_proxy::LokiEntry>) -> Vec<PoolLogLine> {
+    entries
+        .into_iter()
+        .map(|entry| PoolLogLine {
+            timestamp_ns: entry.timestamp_ns,
+            line: entry.line,
+            labels: entry.labels,
+        })
+        .collect()
+}
+
+fn loki_error(source: k8s_proxy::Error) -> ReplyError {
+    ReplyError {
+        kind: ReplyErrorKind::Unavailable,
+        resource: ResourceKind::Pool,
+        source: "loki_query".to_string(),
+        extra: source.to_string(),
+    }
+}
+
+/// Query logs correlated to `query`'s pool/node scoping over its time range, one page at a time.
+///
+/// Not yet wired to a generated REST route (the OpenAPI spec doesn't expose one in this
+/// snapshot) or a gRPC service method (server-streaming needs the generated proto service this
+/// snapshot doesn't have); this is the callable business logic the route/RPC would delegate to
+/// once those exist.
+pub async fn get_pool_logs(
+    source: &dyn LogSource,
+    query: &PoolLogQuery,
+) -> Result<(Vec<PoolLogLine>, Option<i64>), RestError<RestJsonError>> {
+    let selector = query.log_ql_selector();
+    let end_ns = query.page_token.unwrap_or(query.end_ns);
+    let lines = source
+        .query_range(&selector, query.start_ns, end_ns, query.limit)
+        .await?;
+    let next_page_token = lines.last().map(|line| line.timestamp_ns);
+    Ok((lines, next_page_token))
+}
+
+/// Follow new lines matching `query` as they arrive, polling every [`TAIL_POLL_PERIOD`]; mirrors
+/// the follow/tail mode of the (not yet wired) server-streaming gRPC response.
+pub fn tail_pool_logs(
+    source: Arc<dyn LogSource>,
+    query: PoolLogQuery,
+) -> mpsc::Receiver<Result<PoolLogLine, ReplyError>> {
+    let (tx, rx) = mpsc::channel(64);
+    tokio::spawn(async move {
+        let selector = query.log_ql_selector();
+        let mut since_ns = query.start_ns;
+        let mut interval = tokio::time::interval(TAIL_POLL_PERIOD);
+        loop {
+            interval.tick().await;
+            match source.tail_once(&selector, since_ns).await {
+                Ok(lines) => {
+                    for line in lines {
+                        since_ns = since_ns.max(line.timestamp_ns);
+                        if tx.send(Ok(line)).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+                Err(error) => {
+                    let _ = tx.send(Err(error)).await;
+                    return;
+                }
+            }
+        }
+    });
+    rx
+}
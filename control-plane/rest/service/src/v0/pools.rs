@@ -1,6 +1,6 @@
 use super::*;
-use grpc::operations::pool::traits::PoolOperations;
-use stor_port::types::v0::transport::{DestroyPool, Filter};
+use grpc::operations::pool::traits::{PoolOperationError, PoolOperations};
+use stor_port::types::v0::transport::{DestroyPool, Filter, StartPool, StopPool, UnlockMethod};
 use transport_api::{ReplyError, ReplyErrorKind, ResourceKind};
 
 fn client() -> impl PoolOperations {
@@ -32,10 +32,65 @@ async fn destroy_pool(filter: Filter) -> Result<(), RestError<RestJsonError>> {
             }))
         }
     };
-    client().destroy(&destroy, None).await?;
+    if let Err(error) = client().destroy(&destroy, None).await {
+        let classified = PoolOperationError::classify(error.clone());
+        tracing::warn!(
+            pool.id = %destroy.id,
+            retryable = classified.is_retryable(),
+            reason = ?classified.reason(),
+            "pool destroy failed"
+        );
+        return Err(error.into());
+    }
     Ok(())
 }
 
+/// Start (import) a stopped pool, optionally unlocking its devices if they're encrypted. Not yet
+/// wired to a route (the OpenAPI spec doesn't expose one in this snapshot); callers needing this
+/// today can invoke `client().start(..)` directly.
+pub async fn start_pool(
+    node_id: NodeId,
+    pool_id: PoolId,
+    unlock_method: Option<UnlockMethod>,
+) -> Result<models::Pool, RestError<RestJsonError>> {
+    let start = match unlock_method {
+        Some(unlock_method) => StartPool::new_with_unlock(&node_id, &pool_id, None, unlock_method),
+        None => StartPool::new(&node_id, &pool_id, None),
+    };
+    let pool = client().start(&start, None).await.map_err(|error| {
+        let classified = PoolOperationError::classify(error.clone());
+        tracing::warn!(
+            pool.id = %start.id,
+            retryable = classified.is_retryable(),
+            reason = ?classified.reason(),
+            "pool start failed"
+        );
+        error
+    })?;
+    Ok(pool.into())
+}
+
+/// Stop a pool, taking it offline for maintenance without destroying its on-disk data. Not yet
+/// wired to a route (the OpenAPI spec doesn't expose one in this snapshot); callers needing this
+/// today can invoke `client().stop(..)` directly.
+pub async fn stop_pool(
+    node_id: NodeId,
+    pool_id: PoolId,
+) -> Result<models::Pool, RestError<RestJsonError>> {
+    let stop = StopPool::new(&node_id, &pool_id);
+    let pool = client().stop(&stop, None).await.map_err(|error| {
+        let classified = PoolOperationError::classify(error.clone());
+        tracing::warn!(
+            pool.id = %stop.id,
+            retryable = classified.is_retryable(),
+            reason = ?classified.reason(),
+            "pool stop failed"
+        );
+        error
+    })?;
+    Ok(pool.into())
+}
+
 #[async_trait::async_trait]
 impl apis::actix_server::Pools for RestApi {
     async fn del_node_pool(
@@ -108,6 +163,20 @@ impl apis::actix_server::Pools for RestApi {
     }
 }
 
+/// Destroy a batch of pools identified by `filters`, one at a time, returning a per-item result
+/// so that one partial failure doesn't abort the rest of the batch. Mirrors the destroy path of
+/// the core agent's `Service::batch_pools`; the create/label/unlabel variants of `PoolBatchOp` will gain a
+/// REST route once the OpenAPI spec exposes it.
+pub async fn batch_destroy_pools(
+    filters: Vec<Filter>,
+) -> Vec<Result<(), RestError<RestJsonError>>> {
+    let mut results = Vec::with_capacity(filters.len());
+    for filter in filters {
+        results.push(destroy_pool(filter).await);
+    }
+    results
+}
+
 /// returns pool from pool option and returns an error on non existence
 pub fn pool(pool_id: String, pool: Option<&Pool>) -> Result<Pool, ReplyError> {
     match pool {
@@ -0,0 +1,153 @@
+use serde::{Deserialize, Serialize};
+
+/// The kind of resource a [`ReplyError`] pertains to.
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[allow(missing_docs)]
+pub enum ResourceKind {
+    Unknown,
+    Node,
+    Pool,
+    Replica,
+    ReplicaSnapshot,
+    Nexus,
+    Child,
+    Volume,
+    Snapshot,
+    VolumeSnapshot,
+    VolumeSnapshotClone,
+    AffinityGroup,
+    NvmePath,
+    NvmeSubsystem,
+    Watch,
+    JsonGrpc,
+}
+
+/// Stable, wire-level classification of a [`ReplyError`], analogous to a gRPC/HTTP status code.
+/// Callers branch on this instead of parsing `ReplyError::source`'s human-readable text.
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[allow(missing_docs)]
+pub enum ReplyErrorKind {
+    DeadlineExceeded,
+    Unauthenticated,
+    PermissionDenied,
+    Unavailable,
+    NotFound,
+    AlreadyExists,
+    AlreadyShared,
+    NotShared,
+    AlreadyPublished,
+    NotPublished,
+    Aborted,
+    Internal,
+    InvalidArgument,
+    OutOfRange,
+    Unimplemented,
+    Conflict,
+    FailedPersist,
+    Deleting,
+    ResourceExhausted,
+    FailedPrecondition,
+    /// A per-resource lock is already held by another in-flight operation, as distinct from the
+    /// generic `ServiceBusy` case: the caller can retry once that specific resource's operation
+    /// completes, rather than backing off on the whole service.
+    ResourceLocked,
+    /// A rebuild couldn't start immediately because the per-node/per-pool rebuild concurrency
+    /// limit is already in use; the request has been queued rather than rejected outright.
+    RebuildQueued,
+    ReplicaCreateNumber,
+    ReplicaChangeCount,
+    ReplicaIncrease,
+    ReplicaCountAchieved,
+    VolumeNoReplicas,
+    InUse,
+    Timeout,
+}
+
+/// Error returned by the control-plane's agents in response to a failed request, shared by the
+/// internal gRPC transport and the REST API.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ReplyError {
+    /// Stable classification of the error, eg for client branching.
+    pub kind: ReplyErrorKind,
+    /// The kind of resource the error pertains to.
+    pub resource: ResourceKind,
+    /// Human readable error, built from the originating error's `Display`/`source` chain.
+    pub source: String,
+    /// Additional free-form context, eg the request that was being served.
+    pub extra: String,
+    /// Stable, variant-derived identifier for clients to branch on without string-matching
+    /// `source`'s human-readable text (eg `"VolumeAlreadyPublished"`, `"NoCapacityToOnline"`).
+    /// Populated from the originating error's `strum::AsRefStr`-derived discriminant.
+    pub code: String,
+    /// Optional structured detail attached alongside `extra`, eg a retry hint or quota subject.
+    pub details: Option<ErrorDetails>,
+}
+
+/// Typed error detail attached to a [`ReplyError`] alongside its free-form `extra` text, modelled
+/// on the subset of `google.rpc` error-detail messages (`RetryInfo`, `ErrorInfo`, `QuotaFailure`)
+/// that the io-engine's per-subsystem locks and backpressure responses care about, so the
+/// REST/gRPC boundary can re-emit them to clients instead of making callers parse `extra`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ErrorDetails {
+    /// How long the caller should wait before retrying (`google.rpc.RetryInfo`).
+    pub retry_info: Option<RetryInfo>,
+    /// A machine-readable reason plus free-form metadata (`google.rpc.ErrorInfo`).
+    pub error_info: Option<ErrorInfo>,
+    /// The subject that ran out of quota/resources (`google.rpc.QuotaFailure`).
+    pub quota_failure: Option<QuotaFailure>,
+}
+
+/// See [`ErrorDetails::retry_info`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryInfo {
+    /// How long to wait before the next retry attempt.
+    pub retry_after: std::time::Duration,
+}
+
+/// See [`ErrorDetails::error_info`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ErrorInfo {
+    /// The originating `SvcError` variant name, eg `"ResourceLocked"`.
+    pub reason: String,
+    /// Free-form key/value context, eg the request that was being served.
+    pub metadata: Vec<(String, String)>,
+}
+
+/// See [`ErrorDetails::quota_failure`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct QuotaFailure {
+    /// The resource that ran out of quota/capacity.
+    pub subject: String,
+}
+
+impl std::fmt::Display for ReplyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+impl std::error::Error for ReplyError {}
+
+/// Extension trait to render an error's full `source()` chain as a single string, rather than
+/// just its own `Display`.
+pub trait ErrorChain {
+    /// Walk `self`'s `source()` chain, joining each link's `Display` with `: `.
+    fn full_string(&self) -> String;
+}
+
+impl<T: std::error::Error> ErrorChain for T {
+    fn full_string(&self) -> String {
+        let mut msg = self.to_string();
+        let mut source = self.source();
+        while let Some(error) = source {
+            msg.push_str(": ");
+            msg.push_str(&error.to_string());
+            source = error.source();
+        }
+        msg
+    }
+}
+
+/// Types re-exported for REST/gRPC handlers, eg `transport_api::v0::Pools`.
+pub mod v0 {
+    pub use crate::types::v0::transport::{Pools, Replicas};
+}
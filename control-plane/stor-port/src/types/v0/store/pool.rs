@@ -0,0 +1,46 @@
+use crate::types::v0::transport::{
+    NodeId, PoolDeviceUri, PoolEncryption, PoolId, PoolRunState, PoolUuid,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Key/value labels attached to a pool.
+pub type PoolLabel = HashMap<String, String>;
+
+/// User specification of a pool, as persisted by the control plane.
+///
+/// This is the control plane's desired state for the pool; reconcilers drive the data-plane
+/// towards it. `run_state` is the administrative start/stop toggle: it's deliberately kept
+/// separate from the reported `PoolStatus` (which reflects what the data-plane actually sees)
+/// so that an operator-initiated stop survives io-engine restarts instead of being undone by
+/// auto-reimport.
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolSpec {
+    /// Id of the pool.
+    pub id: PoolId,
+    /// Id of the io-engine instance.
+    pub node: NodeId,
+    /// Disk device paths or URIs claimed by the pool.
+    pub disks: Vec<PoolDeviceUri>,
+    /// Labels set on the pool.
+    pub labels: Option<PoolLabel>,
+    /// The pool's uuid, if known.
+    pub uuid: Option<PoolUuid>,
+    /// Encrypt the pool's devices at rest with the given cipher and key, if set.
+    pub encryption: Option<PoolEncryption>,
+    /// The administratively desired run-state (started/stopped) of the pool.
+    ///
+    /// Specs persisted before this field was introduced have no value on disk; `#[serde(default)]`
+    /// decodes those as `PoolRunState::Started` (its `Default` impl), so every pre-existing pool
+    /// keeps running across the upgrade instead of being treated as stopped.
+    #[serde(default)]
+    pub run_state: PoolRunState,
+}
+
+impl PoolSpec {
+    /// Whether the pool is administratively desired to be started (imported).
+    pub fn desired_started(&self) -> bool {
+        self.run_state == PoolRunState::Started
+    }
+}
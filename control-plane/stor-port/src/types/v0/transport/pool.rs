@@ -27,6 +27,12 @@ pub enum PoolStatus {
     Degraded = 2,
     /// The pool is completely inaccessible.
     Faulted = 3,
+    /// The pool was administratively stopped and is not imported on its node. It stays down
+    /// across io-engine restarts rather than being auto-reimported.
+    Stopped = 4,
+    /// The pool is encrypted and present on its node, but not yet unlocked: its devices aren't
+    /// readable until a `StartPool` carrying the right `UnlockMethod` is issued.
+    Locked = 5,
 }
 
 impl Default for PoolStatus {
@@ -40,6 +46,8 @@ impl From<i32> for PoolStatus {
             1 => Self::Online,
             2 => Self::Degraded,
             3 => Self::Faulted,
+            4 => Self::Stopped,
+            5 => Self::Locked,
             _ => Self::Unknown,
         }
     }
@@ -51,10 +59,33 @@ impl From<PoolStatus> for models::PoolStatus {
             PoolStatus::Online => Self::Online,
             PoolStatus::Degraded => Self::Degraded,
             PoolStatus::Faulted => Self::Faulted,
+            PoolStatus::Stopped => Self::Stopped,
+            PoolStatus::Locked => Self::Locked,
         }
     }
 }
 
+/// The desired run-state of a pool, as recorded on its `PoolSpec`.
+///
+/// Drives whether the control plane (re-)imports the pool on its node: an imported pool that's
+/// administratively stopped must stay down across io-engine restarts instead of being silently
+/// brought back online.
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub enum PoolRunState {
+    /// The pool should be imported/created on its node.
+    Started,
+    /// The pool should remain stopped on its node.
+    Stopped,
+}
+
+impl Default for PoolRunState {
+    /// Pre-existing specs, persisted before this field was introduced, are treated as `Started`
+    /// so that pools already up keep running across the upgrade.
+    fn default() -> Self {
+        Self::Started
+    }
+}
+
 /// Control-Plane Pool state information.
 #[derive(Serialize, Deserialize, Default, Debug, Clone, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -100,6 +131,11 @@ pub struct PoolState {
     pub committed: Option<u64>,
     /// labels to be set on the pool
     pub labels: Option<PoolLabel>,
+    /// Whether the pool was provisioned with encryption at rest (see `CreatePool::encryption`).
+    /// Kept separate from `status` so it stays visible once a `Locked` pool transitions to
+    /// `Online` after a successful unlock.
+    #[serde(default)]
+    pub encrypted: bool,
 }
 
 impl From<CtrlPoolState> for models::PoolState {
@@ -120,7 +156,7 @@ impl From<CtrlPoolState> for models::PoolState {
 rpc_impl_string_id!(PoolId, "ID of a pool");
 rpc_impl_string_uuid!(PoolUuid, "UUID of a pool");
 
-// online > degraded > unknown/faulted
+// online > degraded > unknown/faulted/stopped/locked
 impl PartialOrd for PoolStatus {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         match self {
@@ -129,24 +165,48 @@ impl PartialOrd for PoolStatus {
                 PoolStatus::Online => Some(Ordering::Less),
                 PoolStatus::Degraded => Some(Ordering::Less),
                 PoolStatus::Faulted => None,
+                PoolStatus::Stopped => None,
+                PoolStatus::Locked => None,
             },
             PoolStatus::Online => match other {
                 PoolStatus::Unknown => Some(Ordering::Greater),
                 PoolStatus::Online => Some(Ordering::Equal),
                 PoolStatus::Degraded => Some(Ordering::Greater),
                 PoolStatus::Faulted => Some(Ordering::Greater),
+                PoolStatus::Stopped => Some(Ordering::Greater),
+                PoolStatus::Locked => Some(Ordering::Greater),
             },
             PoolStatus::Degraded => match other {
                 PoolStatus::Unknown => Some(Ordering::Greater),
                 PoolStatus::Online => Some(Ordering::Less),
                 PoolStatus::Degraded => Some(Ordering::Equal),
                 PoolStatus::Faulted => Some(Ordering::Greater),
+                PoolStatus::Stopped => Some(Ordering::Greater),
+                PoolStatus::Locked => Some(Ordering::Greater),
             },
             PoolStatus::Faulted => match other {
                 PoolStatus::Unknown => None,
                 PoolStatus::Online => Some(Ordering::Less),
                 PoolStatus::Degraded => Some(Ordering::Less),
                 PoolStatus::Faulted => Some(Ordering::Equal),
+                PoolStatus::Stopped => None,
+                PoolStatus::Locked => None,
+            },
+            PoolStatus::Stopped => match other {
+                PoolStatus::Unknown => None,
+                PoolStatus::Online => Some(Ordering::Less),
+                PoolStatus::Degraded => Some(Ordering::Less),
+                PoolStatus::Faulted => None,
+                PoolStatus::Stopped => Some(Ordering::Equal),
+                PoolStatus::Locked => None,
+            },
+            PoolStatus::Locked => match other {
+                PoolStatus::Unknown => None,
+                PoolStatus::Online => Some(Ordering::Less),
+                PoolStatus::Degraded => Some(Ordering::Less),
+                PoolStatus::Faulted => None,
+                PoolStatus::Stopped => None,
+                PoolStatus::Locked => Some(Ordering::Equal),
             },
         }
     }
@@ -276,6 +336,43 @@ impl From<PoolDeviceUri> for String {
     }
 }
 
+/// Cipher used to encrypt a pool's underlying devices at rest.
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub enum PoolEncryptionCipher {
+    /// AES-256 in XTS mode, the data-plane's default at-rest cipher.
+    Aes256Xts,
+}
+
+/// Where the key material for an encrypted pool comes from.
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub enum PoolKeySource {
+    /// A reference (by name) to a passphrase held in the configured secret backend.
+    PassphraseRef(String),
+    /// A policy-bound token, exchanged with an external KMS for the actual key on each unlock.
+    PolicyToken(String),
+}
+
+/// Encryption descriptor for a new pool: the cipher to encrypt with and where its key comes
+/// from. Present on `CreatePool` only when the pool should be encrypted at rest.
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolEncryption {
+    /// The cipher used to encrypt the pool's devices.
+    pub cipher: PoolEncryptionCipher,
+    /// Where the key used to bind the pool comes from.
+    pub key: PoolKeySource,
+}
+
+/// How to unlock an already-encrypted pool's devices when importing or starting it. Supplied at
+/// unlock time, as opposed to `PoolKeySource` which binds the key at creation time.
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub enum UnlockMethod {
+    /// Unlock with a passphrase held in the configured secret backend, by key name.
+    Passphrase(String),
+    /// Unlock by exchanging a policy-bound token with an external KMS for the key.
+    PolicyToken(String),
+}
+
 /// Create Pool Request.
 #[derive(Serialize, Deserialize, Default, Debug, Clone, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -288,6 +385,8 @@ pub struct CreatePool {
     pub disks: Vec<PoolDeviceUri>,
     /// Labels to be set on the pool.
     pub labels: Option<PoolLabel>,
+    /// Encrypt the pool's devices at rest with the given cipher and key, if set.
+    pub encryption: Option<PoolEncryption>,
 }
 
 impl CreatePool {
@@ -303,6 +402,20 @@ impl CreatePool {
             id: id.clone(),
             disks: disks.to_vec(),
             labels: labels.clone(),
+            encryption: None,
+        }
+    }
+    /// Create new `Self` with an encryption descriptor.
+    pub fn new_encrypted(
+        node: &NodeId,
+        id: &PoolId,
+        disks: &[PoolDeviceUri],
+        labels: &Option<PoolLabel>,
+        encryption: PoolEncryption,
+    ) -> Self {
+        Self {
+            encryption: Some(encryption),
+            ..Self::new(node, id, disks, labels)
         }
     }
 }
@@ -319,6 +432,8 @@ pub struct ImportPool {
     pub disks: Vec<PoolDeviceUri>,
     /// The pool uuid if specified.
     pub uuid: Option<PoolUuid>,
+    /// How to unlock the pool's devices, if they're encrypted.
+    pub unlock_method: Option<UnlockMethod>,
 }
 
 impl ImportPool {
@@ -329,6 +444,7 @@ impl ImportPool {
             id: id.clone(),
             disks: disks.to_vec(),
             uuid: None,
+            unlock_method: None,
         }
     }
 }
@@ -348,3 +464,123 @@ impl DestroyPool {
         Self { node, id }
     }
 }
+
+/// Start Pool Request.
+/// Brings up (imports) a pool that's currently stopped, without disturbing its stored data.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StartPool {
+    /// Id of the io-engine instance.
+    pub node: NodeId,
+    /// Id of the pool.
+    pub id: PoolId,
+    /// The pool uuid, if known, used to confirm identity with the on-disk pool.
+    pub uuid: Option<PoolUuid>,
+    /// How to unlock the pool's devices, if they're encrypted. Required to bring an encrypted,
+    /// locked pool back to `Online`.
+    pub unlock_method: Option<UnlockMethod>,
+}
+impl StartPool {
+    /// Create a new `Self` from the given parameters.
+    pub fn new(node: &NodeId, id: &PoolId, uuid: Option<PoolUuid>) -> Self {
+        Self {
+            node: node.clone(),
+            id: id.clone(),
+            uuid,
+            unlock_method: None,
+        }
+    }
+    /// Create a new `Self` that unlocks the pool with the given `unlock_method`.
+    pub fn new_with_unlock(
+        node: &NodeId,
+        id: &PoolId,
+        uuid: Option<PoolUuid>,
+        unlock_method: UnlockMethod,
+    ) -> Self {
+        Self {
+            unlock_method: Some(unlock_method),
+            ..Self::new(node, id, uuid)
+        }
+    }
+}
+
+/// Stop Pool Request.
+/// Takes a pool offline for maintenance without destroying its on-disk data; it stays down
+/// across io-engine restarts until explicitly started again.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StopPool {
+    /// Id of the io-engine instance.
+    pub node: NodeId,
+    /// Id of the pool.
+    pub id: PoolId,
+}
+impl StopPool {
+    /// Create a new `Self` from the given parameters.
+    pub fn new(node: &NodeId, id: &PoolId) -> Self {
+        Self {
+            node: node.clone(),
+            id: id.clone(),
+        }
+    }
+}
+
+/// Label Pool Request.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelPool {
+    /// Id of the pool.
+    pub id: PoolId,
+    /// Labels to be set on the pool.
+    pub labels: PoolLabel,
+    /// Whether to overwrite an existing label with the same key.
+    pub overwrite: bool,
+}
+
+/// Unlabel Pool Request.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UnlabelPool {
+    /// Id of the pool.
+    pub id: PoolId,
+    /// Key of the label to be removed.
+    pub label_key: String,
+}
+
+/// A single heterogeneous pool operation that may be part of a `BatchPoolRequest`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum PoolBatchOp {
+    /// Create a pool.
+    Create(CreatePool),
+    /// Destroy a pool.
+    Destroy(DestroyPool),
+    /// Label a pool.
+    Label(LabelPool),
+    /// Unlabel a pool.
+    Unlabel(UnlabelPool),
+}
+
+/// Ordered list of heterogeneous pool operations to execute as a batch.
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+pub struct BatchPoolRequest {
+    /// The ordered operations to apply, one-by-one.
+    pub ops: Vec<PoolBatchOp>,
+}
+
+/// The result of a single operation within a `BatchPoolRequest`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum PoolBatchOpResult {
+    /// The operation succeeded and produced a pool (create/label/unlabel).
+    Pool(Pool),
+    /// The operation succeeded and produced nothing (destroy).
+    Empty,
+    /// The operation failed; the rest of the batch still runs.
+    Error(crate::transport_api::ReplyError),
+}
+
+/// Per-item results of a `BatchPoolRequest`, in the same order as the request.
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+pub struct BatchPoolReply {
+    /// One result per requested operation.
+    pub results: Vec<PoolBatchOpResult>,
+}
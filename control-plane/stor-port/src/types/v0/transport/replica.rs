@@ -0,0 +1,58 @@
+use super::*;
+
+use serde::{Deserialize, Serialize};
+
+/// A single heterogeneous replica operation that may be part of a `BatchReplicaRequest`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum ReplicaBatchOp {
+    /// Create a replica.
+    Create(CreateReplica),
+    /// Destroy a replica.
+    Destroy(DestroyReplica),
+    /// Share a replica.
+    Share(ShareReplica),
+    /// Unshare a replica.
+    Unshare(UnshareReplica),
+}
+
+impl ReplicaBatchOp {
+    /// The uuid of the replica this operation targets, used to establish a stable lock-ordering
+    /// across a batch so concurrently executing operations can't deadlock against each other.
+    pub fn uuid(&self) -> &ReplicaId {
+        match self {
+            Self::Create(op) => &op.uuid,
+            Self::Destroy(op) => &op.uuid,
+            Self::Share(op) => &op.uuid,
+            Self::Unshare(op) => &op.uuid,
+        }
+    }
+}
+
+/// Ordered list of heterogeneous replica operations to execute as a batch. Operations are
+/// internally reordered by replica uuid before being run, so submission order doesn't need to
+/// match lock-acquisition order; the per-item results preserve the caller's original order.
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+pub struct BatchReplicaRequest {
+    /// The operations to apply.
+    pub ops: Vec<ReplicaBatchOp>,
+}
+
+/// The result of a single operation within a `BatchReplicaRequest`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum ReplicaBatchOpResult {
+    /// The operation succeeded and produced a replica (create).
+    Replica(Replica),
+    /// The operation succeeded and produced a share uri (share).
+    Uri(String),
+    /// The operation succeeded and produced nothing (destroy/unshare).
+    Empty,
+    /// The operation failed; the rest of the batch still runs.
+    Error(crate::transport_api::ReplyError),
+}
+
+/// Per-item results of a `BatchReplicaRequest`, in the same order as the request.
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+pub struct BatchReplicaReply {
+    /// One result per requested operation, in the order the operations were submitted.
+    pub results: Vec<ReplicaBatchOpResult>,
+}
@@ -1,15 +1,28 @@
 use std::convert::TryFrom;
 use tokio::time::{sleep, Duration};
-use tonic::transport::Endpoint;
+use tonic::transport::{Certificate, ClientTlsConfig, Endpoint, Identity};
 
 use crate::infra::{
     async_trait, Builder, ComponentAction, ComposeTest, Error, HaClusterAgent, StartOptions,
 };
 use composer::{Binary, ContainerSpec};
 
+// `StartOptions` (and the CLI flags that would populate `cluster_rpc_secret`/
+// `cluster_rpc_secret_file`/`cluster_tls_cert`/`cluster_tls_key`/`cluster_tls_ca` on it) isn't
+// defined anywhere in this checkout - only this file, the consuming half, is present. Those
+// fields belong on `StartOptions` itself, alongside `agents_env`/`cluster_fast_requeue`/
+// `cluster_label`/`eventing`, which this module already reads the same way.
+
 #[async_trait]
 impl ComponentAction for HaClusterAgent {
     fn configure(&self, options: &StartOptions, cfg: Builder) -> Result<Builder, Error> {
+        if options.cluster_rpc_secret.is_some() && options.cluster_rpc_secret_file.is_some() {
+            return Err(anyhow::anyhow!(
+                "only one of an inline rpc secret or --rpc-secret-file may be specified"
+            )
+            .into());
+        }
+
         let mut spec = ContainerSpec::from_binary(
             "agent-ha-cluster",
             Binary::from_dbg("agent-ha-cluster").with_args(vec!["-g=[::]:11500"]),
@@ -38,6 +51,19 @@ impl ComponentAction for HaClusterAgent {
             spec = spec.with_args(vec!["--events-url", nats_server_url]);
         };
 
+        if let Some(secret_file) = &options.cluster_rpc_secret_file {
+            spec = spec.with_args(vec!["--rpc-secret-file", secret_file]);
+        }
+        if let Some(cert) = &options.cluster_tls_cert {
+            spec = spec.with_args(vec!["--tls-cert", cert]);
+        }
+        if let Some(key) = &options.cluster_tls_key {
+            spec = spec.with_args(vec!["--tls-key", key]);
+        }
+        if let Some(ca) = &options.cluster_tls_ca {
+            spec = spec.with_args(vec!["--tls-ca", ca]);
+        }
+
         Ok(cfg.add_container_spec(spec))
     }
 
@@ -46,17 +72,24 @@ impl ComponentAction for HaClusterAgent {
         Ok(())
     }
 
-    async fn wait_on(&self, _options: &StartOptions, cfg: &ComposeTest) -> Result<(), Error> {
+    async fn wait_on(&self, options: &StartOptions, cfg: &ComposeTest) -> Result<(), Error> {
+        // Same client credential the cluster-agent itself was started with, so the readiness
+        // probe below is authenticated the same way a real caller would be.
+        let tls_config = Self::client_tls_config(options);
+
         // Wait till cluster-agent's gRPC server is ready to server the request
         loop {
-            match Endpoint::try_from(format!(
+            let endpoint = Endpoint::try_from(format!(
                 "https://{}:11500",
                 cfg.container_ip("agent-ha-cluster")
             ))?
-            .connect_timeout(Duration::from_millis(100))
-            .connect()
-            .await
-            {
+            .connect_timeout(Duration::from_millis(100));
+            let endpoint = match &tls_config {
+                Some(tls_config) => endpoint.tls_config(tls_config.clone())?,
+                None => endpoint,
+            };
+
+            match endpoint.connect().await {
                 Ok(_) => break,
                 Err(_) => sleep(Duration::from_millis(25)).await,
             }
@@ -64,3 +97,29 @@ impl ComponentAction for HaClusterAgent {
         Ok(())
     }
 }
+
+impl HaClusterAgent {
+    /// Build the client TLS config used to dial the cluster-agent's gRPC endpoint, from whichever
+    /// of `--tls-ca`/`--tls-cert`/`--tls-key` were passed to it in [`ComponentAction::configure`].
+    /// Returns `None` when none were set, so callers keep connecting over a plain TLS channel.
+    fn client_tls_config(options: &StartOptions) -> Option<ClientTlsConfig> {
+        if options.cluster_tls_ca.is_none()
+            && options.cluster_tls_cert.is_none()
+            && options.cluster_tls_key.is_none()
+        {
+            return None;
+        }
+
+        let mut tls_config = ClientTlsConfig::new();
+        if let Some(ca) = &options.cluster_tls_ca {
+            let pem = std::fs::read_to_string(ca).expect("readable --tls-ca file");
+            tls_config = tls_config.ca_certificate(Certificate::from_pem(pem));
+        }
+        if let (Some(cert), Some(key)) = (&options.cluster_tls_cert, &options.cluster_tls_key) {
+            let cert_pem = std::fs::read_to_string(cert).expect("readable --tls-cert file");
+            let key_pem = std::fs::read_to_string(key).expect("readable --tls-key file");
+            tls_config = tls_config.identity(Identity::from_pem(cert_pem, key_pem));
+        }
+        Some(tls_config)
+    }
+}
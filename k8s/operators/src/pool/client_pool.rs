@@ -0,0 +1,95 @@
+//! A small pool of control-plane REST clients, one per configured endpoint, so a rolling restart
+//! of the control plane doesn't wedge every reconcile against one now-dead endpoint. Modeled
+//! loosely on deadpool's/hyper's pool pattern of only handing out a connection that's known to
+//! be open: a background task periodically health-checks every endpoint with a cheap `get_pools`
+//! call, and [`ApiClientPool::current`] hands out whichever endpoint is currently believed
+//! healthy, nearest the last one used.
+
+use openapi::clients::{self, tower::Url};
+use std::{
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+    time::Duration,
+};
+use tracing::{debug, warn};
+
+struct Endpoint {
+    client: clients::tower::ApiClient,
+    url: Url,
+    healthy: AtomicBool,
+}
+
+/// Failover pool of control-plane REST endpoints, with atomic health tracking.
+pub(crate) struct ApiClientPool {
+    endpoints: Vec<Endpoint>,
+    current: AtomicUsize,
+}
+
+impl ApiClientPool {
+    /// Build a pool from one `(endpoint, configuration)` pair per configured control-plane
+    /// replica. All endpoints start out assumed healthy; the background health check in
+    /// [`Self::health_check_periodically`] corrects that as soon as it's run once.
+    pub(crate) fn new(configs: Vec<(Url, clients::tower::Configuration)>) -> Self {
+        let endpoints = configs
+            .into_iter()
+            .map(|(url, cfg)| Endpoint {
+                client: clients::tower::ApiClient::new(cfg),
+                url,
+                healthy: AtomicBool::new(true),
+            })
+            .collect();
+        Self {
+            endpoints,
+            current: AtomicUsize::new(0),
+        }
+    }
+
+    /// Hand out the client for whichever endpoint is currently believed healthy, searching
+    /// starting at the last endpoint used so calls don't bounce between endpoints needlessly.
+    /// Falls back to that same starting endpoint if every one of them is currently marked
+    /// unhealthy, so the operator keeps trying (and can notice a recovery) rather than giving up
+    /// on the control plane entirely.
+    pub(crate) fn current(&self) -> &clients::tower::ApiClient {
+        let len = self.endpoints.len();
+        let start = self.current.load(Ordering::Relaxed) % len;
+        for offset in 0..len {
+            let idx = (start + offset) % len;
+            if self.endpoints[idx].healthy.load(Ordering::Relaxed) {
+                if idx != start {
+                    self.current.store(idx, Ordering::Relaxed);
+                }
+                return &self.endpoints[idx].client;
+            }
+        }
+        &self.endpoints[start].client
+    }
+
+    /// Mark the endpoint that handed out the current client as unhealthy and advance to the next
+    /// one, so the next call (this reconcile's requeue, or the next reconcile) fails over to it
+    /// instead of hitting the same unreachable endpoint again. Callers should invoke this once
+    /// they've seen `Error::Request` (a transport-level failure) or a `SERVICE_UNAVAILABLE`
+    /// response against the control plane.
+    pub(crate) fn mark_current_unhealthy(&self) {
+        let len = self.endpoints.len();
+        let idx = self.current.load(Ordering::Relaxed) % len;
+        self.endpoints[idx].healthy.store(false, Ordering::Relaxed);
+        warn!(endpoint = %self.endpoints[idx].url, "Control-plane endpoint unhealthy, failing over");
+        self.current.store((idx + 1) % len, Ordering::Relaxed);
+    }
+
+    /// Periodically probe every endpoint (not just the current one) with a cheap `get_pools`
+    /// call, so an endpoint that's come back after a rolling restart is noticed even if it isn't
+    /// the one currently in use, and a currently-in-use endpoint that's gone bad is noticed even
+    /// between reconciles rather than only on the next failed call.
+    pub(crate) async fn health_check_periodically(&self, period: Duration) {
+        let mut interval = tokio::time::interval(period);
+        loop {
+            interval.tick().await;
+            for endpoint in &self.endpoints {
+                let healthy = endpoint.client.pools_api().get_pools().await.is_ok();
+                if healthy != endpoint.healthy.swap(healthy, Ordering::Relaxed) {
+                    debug!(endpoint = %endpoint.url, healthy, "Control-plane endpoint health changed");
+                }
+            }
+        }
+    }
+}
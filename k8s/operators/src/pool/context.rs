@@ -1,6 +1,6 @@
 use super::{
     diskpool::v1beta1::{CrPoolState, DiskPool, DiskPoolStatus},
-    error::Error,
+    error::{Error, PoolContext},
 };
 use k8s_openapi::{api::core::v1::Event, apimachinery::pkg::apis::meta::v1::MicroTime};
 use kube::{
@@ -11,12 +11,13 @@ use kube::{
 use openapi::{
     apis::StatusCode,
     clients,
-    models::{CreatePoolBody, Pool},
+    models::{self, CreatePoolBody, Pool, RestJsonError},
 };
 
 use super::{normalize_disk, v1beta1_api};
 use chrono::Utc;
 use kube::api::{Patch, PostParams};
+use rand::Rng;
 use serde_json::json;
 use std::{
     collections::HashMap,
@@ -24,7 +25,9 @@ use std::{
     sync::{Arc, Mutex},
     time::Duration,
 };
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
+
+use super::job_queue::{JobQueue, RetryOutcome};
 
 const WHO_AM_I: &str = "DiskPool Operator";
 const WHO_AM_I_SHORT: &str = "dsp-operator";
@@ -56,25 +59,50 @@ pub(crate) struct OperatorContext {
     k8s: Client,
     /// Hashtable of name and the full last seen CRD
     inventory: tokio::sync::RwLock<HashMap<String, ResourceContext>>,
-    /// HTTP client
-    http: clients::tower::ApiClient,
+    /// Pool of HTTP clients, one per control-plane endpoint, with health-checked failover.
+    http: Arc<super::client_pool::ApiClientPool>,
     /// Interval
     interval: u64,
+    /// Base delay for the capped exponential backoff used on transient errors, see
+    /// [`Self::backoff_delay`].
+    backoff_base: Duration,
+    /// Upper bound the exponential backoff is capped to, see [`Self::backoff_delay`].
+    backoff_max: Duration,
+    /// Whether [`Self::backoff_delay`] applies full jitter on top of the capped delay. Disabling
+    /// this is mostly useful for deterministic testing of the backoff curve itself.
+    backoff_jitter: bool,
+    /// How long a `pools_api()`/`block_devices_api()` call may be pending before
+    /// `poll_timer::call_with_timer` logs a warning and raises a `SlowControlPlane` event.
+    slow_call_threshold: Duration,
+    /// How long a `pools_api()`/`block_devices_api()` call may be pending in total before
+    /// `poll_timer::call_with_timer` gives up on it with `Error::CallTimedOut`.
+    call_timeout: Duration,
 }
 
 impl OperatorContext {
     /// Constructor for Operator context.
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         k8s: Client,
         inventory: tokio::sync::RwLock<HashMap<String, ResourceContext>>,
-        http: clients::tower::ApiClient,
+        http: Arc<super::client_pool::ApiClientPool>,
         interval: u64,
+        backoff_base: Duration,
+        backoff_max: Duration,
+        backoff_jitter: bool,
+        slow_call_threshold: Duration,
+        call_timeout: Duration,
     ) -> Self {
         Self {
             k8s,
             inventory,
             http,
             interval,
+            backoff_base,
+            backoff_max,
+            backoff_jitter,
+            slow_call_threshold,
+            call_timeout,
         }
     }
 
@@ -83,6 +111,31 @@ impl OperatorContext {
         self.inventory.read().await.contains_key(&key)
     }
 
+    /// Capped exponential backoff with full jitter, driven by how many times `reconcile` has
+    /// retried the resource within its current status (`ResourceContext::num_retries`): the delay
+    /// is `min(backoff_max, backoff_base * 2^num_retries)`, then (unless `backoff_jitter` is
+    /// disabled) a uniform random value in `[0, delay]` is picked as the actual requeue duration,
+    /// so a fleet of resources hitting the same transient failure don't all retry in lockstep.
+    pub(crate) fn backoff_delay(&self, num_retries: u32) -> Duration {
+        let capped = self
+            .backoff_base
+            .saturating_mul(2u32.saturating_pow(num_retries))
+            .min(self.backoff_max);
+        if !self.backoff_jitter {
+            return capped;
+        }
+        let jitter_ms = rand::thread_rng().gen_range(0..=capped.as_millis() as u64);
+        Duration::from_millis(jitter_ms)
+    }
+
+    /// Reset the retry counter for the named resource, e.g. once it reaches a healthy steady
+    /// state and should go back to polling at the plain `interval` rather than a backed-off one.
+    pub(crate) async fn reset_retries(&self, name: &str) {
+        if let Some(resource) = self.inventory.write().await.get_mut(name) {
+            resource.num_retries = 0;
+        }
+    }
+
     /// Upsert the potential new CRD into the operator context. If an existing
     /// resource with the same name is present, the old resource is
     /// returned.
@@ -164,7 +217,7 @@ impl ResourceContext {
     ) -> Result<Action, Error> {
         let ctx = resource.ctx.clone();
         if attempt_delete {
-            resource.delete_pool().await?;
+            resource.delete_pool_via_job_queue().await?;
         }
         if ctx.remove(resource.name_any()).await.is_none() {
             // In an unlikely event where we cant remove from inventory. We will requeue and
@@ -185,16 +238,27 @@ impl ResourceContext {
         v1beta1_api(&self.ctx.k8s, &self.namespace().unwrap())
     }
 
-    /// Control plane pool handler.
+    /// The CR name/namespace/node this resource is reconciling, for attaching to error context.
+    fn pool_context(&self) -> PoolContext {
+        PoolContext {
+            name: self.name_any(),
+            namespace: self.namespace().unwrap_or_default(),
+            node: self.spec.node(),
+        }
+    }
+
+    /// Control plane pool handler, from whichever endpoint `self.ctx.http` currently believes is
+    /// healthy.
     fn pools_api(&self) -> &dyn openapi::apis::pools_api::tower::client::Pools {
-        self.ctx.http.pools_api()
+        self.ctx.http.current().pools_api()
     }
 
-    /// Control plane block device handler.
+    /// Control plane block device handler, from whichever endpoint `self.ctx.http` currently
+    /// believes is healthy.
     fn block_devices_api(
         &self,
     ) -> &dyn openapi::apis::block_devices_api::tower::client::BlockDevices {
-        self.ctx.http.block_devices_api()
+        self.ctx.http.current().block_devices_api()
     }
 
     /// Patch the given dsp status to the state provided.
@@ -223,11 +287,21 @@ impl ResourceContext {
     }
 
     /// Mark Pool state as None as couldnt find already provisioned pool in control plane.
-    async fn mark_pool_not_found(&self) -> Result<Action, Error> {
+    ///
+    /// `backoff` distinguishes the transient "couldn't reach the control plane" callers, which
+    /// should requeue with [`OperatorContext::backoff_delay`], from the "control plane reached,
+    /// but it told us the pool is genuinely gone" caller, which keeps the plain fixed delay since
+    /// retrying it faster or slower doesn't change the outcome.
+    async fn mark_pool_not_found(&self, backoff: bool) -> Result<Action, Error> {
         self.patch_status(DiskPoolStatus::not_found(&self.inner.status))
             .await?;
         error!(name = ?self.name_any(), "Pool not found, clearing status");
-        Ok(Action::requeue(Duration::from_secs(30)))
+        let delay = if backoff {
+            self.ctx.backoff_delay(self.num_retries)
+        } else {
+            Duration::from_secs(30)
+        };
+        Ok(Action::requeue(delay))
     }
 
     /// Patch the resource state to creating.
@@ -249,9 +323,106 @@ impl ResourceContext {
         Ok(Action::requeue(Duration::from_secs(self.ctx.interval)))
     }
 
+    /// Capped exponential backoff (with jitter), in whole seconds, for this resource's current
+    /// `num_retries`; used to fill in the `timeout` field of the error variants that carry their
+    /// own requeue duration (same convention as `Error::Duplicate`/`Error::SpecError`).
+    fn backoff_timeout(&self) -> u32 {
+        self.ctx.backoff_delay(self.num_retries).as_secs() as u32
+    }
+
+    /// Raise the throttled "SlowControlPlane" k8s event for a named call once
+    /// `poll_timer::call_with_timer` reports it crossed the slow-call threshold. The message
+    /// carries no elapsed value, so repeated stalls on the same call dedupe through
+    /// `Self::k8s_notify`'s own seen-message cache instead of flooding the CR's events.
+    async fn notify_slow_call(&self, call: &'static str) {
+        self.k8s_notify(
+            "SlowControlPlane",
+            "Slow",
+            &format!(
+                "Control-plane call '{call}' has been pending longer than {:?}",
+                self.ctx.slow_call_threshold
+            ),
+            "Warning",
+        )
+        .await;
+    }
+
+    /// Build the `Error::CallTimedOut` for a named call that `poll_timer::call_with_timer` gave
+    /// up on, after notifying and logging it.
+    async fn call_timed_out(&self, call: &'static str) -> Error {
+        let message = format!(
+            "Control-plane call '{call}' timed out after {:?}",
+            self.ctx.call_timeout
+        );
+        self.k8s_notify("Create or Import Failure", "Failure", &message, "Critical")
+            .await;
+        error!("{message}");
+        // A call that hung this long almost always means the endpoint it was sent to is the
+        // problem, not the pool itself; fail over so the retry this error triggers has a chance
+        // to land somewhere healthy.
+        self.ctx.http.mark_current_unhealthy();
+        Error::CallTimedOut {
+            ctx: self.pool_context(),
+            call,
+            timeout: self.backoff_timeout(),
+        }
+    }
+
     /// Create or import the pool, on failure try again.
     #[tracing::instrument(fields(name = ?self.name_any(), status = ?self.status) skip(self))]
+    /// Dequeue (or requeue) this resource's `create_or_import` job from the persistent
+    /// [`JobQueue`] before attempting [`Self::create_or_import_inner`], so a crash mid-attempt is
+    /// resumed rather than silently lost, and a repeatedly failing attempt backs off across
+    /// operator restarts instead of just within `ResourceContext::num_retries`'s in-memory count.
     pub(crate) async fn create_or_import(self) -> Result<Action, Error> {
+        if self.spec.stopped() {
+            // The stopped check is a desired-state short-circuit, not a retryable attempt, so it
+            // stays ahead of the job queue gating below.
+            return self.create_or_import_inner().await;
+        }
+
+        let name = self.name_any();
+        let namespace = self.namespace().unwrap_or_default();
+        let k8s = self.ctx.k8s.clone();
+
+        let mut queue = JobQueue::load(&k8s, &namespace).await?;
+        let Some(attempt) = queue.dequeue(&k8s, &namespace, &name).await? else {
+            // Still within the previous failed attempt's back-off; let the controller's normal
+            // requeue drive the next check rather than busy-polling.
+            return Ok(Action::requeue(Duration::from_secs(5)));
+        };
+        debug!(%name, attempt, "dequeued create_or_import job");
+
+        match self.create_or_import_inner().await {
+            Ok(action) => {
+                queue.complete(&k8s, &namespace, &name).await?;
+                Ok(action)
+            }
+            Err(error) => {
+                match queue.fail(&k8s, &namespace, &name, error.to_string()).await? {
+                    RetryOutcome::Retry { attempt, delay } => {
+                        warn!(%name, attempt, ?delay, %error, "create_or_import failed, requeued");
+                    }
+                    RetryOutcome::Exhausted { attempt } => {
+                        error!(%name, attempt, %error, "create_or_import exhausted job queue retries");
+                    }
+                }
+                Err(error)
+            }
+        }
+    }
+
+    /// The actual create-or-import attempt, previously named `create_or_import` before the
+    /// persistent [`JobQueue`] wrapper was introduced around it.
+    async fn create_or_import_inner(self) -> Result<Action, Error> {
+        if self.spec.stopped() {
+            // The CR was created (or re-synced) with `stopped: true` before ever being
+            // imported: go straight to `Stopped` rather than importing it just to tear it
+            // back down again on the next reconcile.
+            let _ = self.patch_status(DiskPoolStatus::stopped(&self.status)).await?;
+            return Ok(Action::await_change());
+        }
+
         info!(" &self.spec.node() {:?}", &self.spec.node());
         info!("topology {:?}", &self.spec.topology());
         
@@ -264,12 +435,32 @@ impl ResourceContext {
             labels.insert(key, value);
         }
         
-        let body = CreatePoolBody::new_all(self.spec.disks(), labels);
-        match self
-            .pools_api()
-            .put_node_pool(&self.spec.node(), &self.name_any(), body)
-            .await
+        let body = match self.spec.encryption() {
+            // A missing `encryption` block means plaintext, so existing CRs (created before
+            // this field existed) migrate for free rather than needing a default value filled
+            // in.
+            None => CreatePoolBody::new_all(self.spec.disks(), labels),
+            Some(encryption) => {
+                CreatePoolBody::new_all_encrypted(self.spec.disks(), labels, encryption_body(encryption))
+            }
+        };
+        let (put_result, slow) = match super::poll_timer::call_with_timer(
+            &self.name_any(),
+            "put_node_pool",
+            self.ctx.slow_call_threshold,
+            self.ctx.call_timeout,
+            self.pools_api()
+                .put_node_pool(&self.spec.node(), &self.name_any(), body),
+        )
+        .await
         {
+            Ok(outcome) => outcome,
+            Err(_) => return Err(self.call_timed_out("put_node_pool").await),
+        };
+        if slow {
+            self.notify_slow_call("put_node_pool").await;
+        }
+        match put_result {
             Ok(_) => {}
             Err(clients::tower::Error::Response(response))
                 if response.status() == clients::tower::StatusCode::UNPROCESSABLE_ENTITY =>
@@ -279,33 +470,42 @@ impl ResourceContext {
                 return self.mark_unknown().await;
             }
             Err(error) => {
-                return match self
-                    .block_devices_api()
-                    .get_node_block_devices(&self.spec.node(), Some(true))
-                    .await
+                if matches!(error, clients::tower::Error::Request(_)) {
+                    self.ctx.http.mark_current_unhealthy();
+                }
+                let (devices_result, slow) = match super::poll_timer::call_with_timer(
+                    &self.name_any(),
+                    "get_node_block_devices",
+                    self.ctx.slow_call_threshold,
+                    self.ctx.call_timeout,
+                    self.block_devices_api()
+                        .get_node_block_devices(&self.spec.node(), Some(true)),
+                )
+                .await
                 {
+                    Ok(outcome) => outcome,
+                    Err(_) => return Err(self.call_timed_out("get_node_block_devices").await),
+                };
+                if slow {
+                    self.notify_slow_call("get_node_block_devices").await;
+                }
+                return match devices_result {
                     Ok(response) => {
+                        let device = self.spec.disks()[0].clone();
                         if !response.into_body().into_iter().any(|b| {
-                            b.devname == normalize_disk(&self.spec.disks()[0])
-                                || b.devlinks
-                                    .iter()
-                                    .any(|d| *d == normalize_disk(&self.spec.disks()[0]))
+                            b.devname == normalize_disk(&device)
+                                || b.devlinks.iter().any(|d| *d == normalize_disk(&device))
                         }) {
-                            self.k8s_notify(
-                                "Create or import",
-                                "Missing",
-                                &format!(
-                                    "The block device(s): {} can not be found",
-                                    &self.spec.disks()[0]
-                                ),
-                                "Warn",
-                            )
-                            .await;
-                            error!(
-                                "The block device(s): {} can not be found",
-                                &self.spec.disks()[0]
-                            );
-                            Err(error.into())
+                            let message = format!("The block device(s): {device} can not be found");
+                            self.k8s_notify("Create or import", "Missing", &message, "Warn")
+                                .await;
+                            error!("{message}");
+                            Err(Error::PoolDeviceRejected {
+                                ctx: self.pool_context(),
+                                device,
+                                message,
+                                timeout: self.backoff_timeout(),
+                            })
                         } else {
                             self.k8s_notify(
                                 "Create or Import Failure",
@@ -315,7 +515,12 @@ impl ResourceContext {
                             )
                             .await;
                             error!("Unable to create or import pool {}", error);
-                            Err(error.into())
+                            Err(Error::PoolRpcFailed {
+                                ctx: self.pool_context(),
+                                status: rpc_status_code(&error),
+                                message: error.to_string(),
+                                timeout: self.backoff_timeout(),
+                            })
                         }
                     }
                     Err(clients::tower::Error::Response(response))
@@ -329,7 +534,12 @@ impl ResourceContext {
                         )
                         .await;
                         error!("Unable to find io-engine node {}", &self.spec.node());
-                        Err(error.into())
+                        Err(Error::PoolRpcFailed {
+                            ctx: self.pool_context(),
+                            status: rpc_status_code(&error),
+                            message: format!("io-engine node {} not found", &self.spec.node()),
+                            timeout: self.backoff_timeout(),
+                        })
                     }
                     _ => {
                         self.k8s_notify(
@@ -340,7 +550,12 @@ impl ResourceContext {
                         )
                         .await;
                         error!("Unable to create or import pool {}", error);
-                        Err(error.into())
+                        Err(Error::PoolRpcFailed {
+                            ctx: self.pool_context(),
+                            status: rpc_status_code(&error),
+                            message: error.to_string(),
+                            timeout: self.backoff_timeout(),
+                        })
                     }
                 };
             }
@@ -357,13 +572,64 @@ impl ResourceContext {
         self.pool_created().await
     }
 
+    /// Dequeue (or requeue) this resource's destroy job from the persistent [`JobQueue`] before
+    /// attempting [`Self::delete_pool`], the destroy-side counterpart of
+    /// [`Self::create_or_import`]'s job queue wrapper. Keyed as `"<name>/destroy"` rather than
+    /// plain `name` so a destroy job can never collide with a create-or-import job still queued
+    /// under the same CR name.
+    async fn delete_pool_via_job_queue(&self) -> Result<Action, Error> {
+        let name = format!("{}/destroy", self.name_any());
+        let namespace = self.namespace().unwrap_or_default();
+        let k8s = self.ctx.k8s.clone();
+
+        let mut queue = JobQueue::load(&k8s, &namespace).await?;
+        let Some(attempt) = queue.dequeue(&k8s, &namespace, &name).await? else {
+            // Still within the previous failed attempt's back-off: report an error so that
+            // `delete_finalizer` keeps the resource until the pool has actually been destroyed.
+            return Err(Error::Generic {
+                message: format!("Destroy of pool '{}' is backing off after a failed attempt", self.name_any()),
+            });
+        };
+        debug!(%name, attempt, "dequeued destroy job");
+
+        match self.delete_pool().await {
+            Ok(action) => {
+                queue.complete(&k8s, &namespace, &name).await?;
+                Ok(action)
+            }
+            Err(error) => {
+                match queue.fail(&k8s, &namespace, &name, error.to_string()).await? {
+                    RetryOutcome::Retry { attempt, delay } => {
+                        warn!(%name, attempt, ?delay, %error, "delete_pool failed, requeued");
+                    }
+                    RetryOutcome::Exhausted { attempt } => {
+                        error!(%name, attempt, %error, "delete_pool exhausted job queue retries");
+                    }
+                }
+                Err(error)
+            }
+        }
+    }
+
     /// Delete the pool from the io-engine instance
     #[tracing::instrument(fields(name = ?self.name_any(), status = ?self.status) skip(self))]
     async fn delete_pool(&self) -> Result<Action, Error> {
-        let res = self
-            .pools_api()
-            .del_node_pool(&self.spec.node(), &self.name_any())
-            .await;
+        let (res, slow) = match super::poll_timer::call_with_timer(
+            &self.name_any(),
+            "del_node_pool",
+            self.ctx.slow_call_threshold,
+            self.ctx.call_timeout,
+            self.pools_api()
+                .del_node_pool(&self.spec.node(), &self.name_any()),
+        )
+        .await
+        {
+            Ok(outcome) => outcome,
+            Err(_) => return Err(self.call_timed_out("del_node_pool").await),
+        };
+        if slow {
+            self.notify_slow_call("del_node_pool").await;
+        }
 
         match res {
             Ok(_) => {
@@ -392,14 +658,110 @@ impl ResourceContext {
         }
     }
 
+    /// Stop the pool, tearing down its import on the node while leaving its control-plane spec
+    /// (and therefore its on-disk metadata) intact, so it can be brought back with
+    /// `Self::create_or_import`. This is distinct from `Self::delete_pool`, which destroys the
+    /// pool outright; it calls the control plane's dedicated stop operation instead of
+    /// `del_node_pool` for that reason (borrowed from stratisd's start/stop model, which replaced
+    /// lock/unlock there for the same "stays known but torn down" semantics).
+    #[tracing::instrument(fields(name = ?self.name_any(), status = ?self.status) skip(self))]
+    pub(crate) async fn stop_pool(self) -> Result<Action, Error> {
+        let res = self
+            .pools_api()
+            .put_node_pool_stop(&self.spec.node(), &self.name_any())
+            .await;
+
+        match res {
+            Ok(_) => {
+                self.k8s_notify("Stopped pool", "Stop", "The pool has been stopped", "Normal")
+                    .await;
+                let _ = self.patch_status(DiskPoolStatus::stopped(&self.status)).await?;
+                Ok(Action::await_change())
+            }
+            Err(clients::tower::Error::Response(response))
+                if response.status() == StatusCode::NOT_FOUND =>
+            {
+                self.k8s_notify(
+                    "Stopped pool",
+                    "Stop",
+                    "The pool was already stopped or removed",
+                    "Normal",
+                )
+                .await;
+                let _ = self.patch_status(DiskPoolStatus::stopped(&self.status)).await?;
+                Ok(Action::await_change())
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Issue a "start with unlock-method" call for a pool the control plane reports as present
+    /// but locked (encrypted, not yet unlocked), using the CR's `spec.encryption.unlock_method`
+    /// (a directly-supplied key reference, or a Clevis/network-bound style provider). Emits a
+    /// distinct notification on failure rather than silently retrying like `pool_check`'s other
+    /// `Unknown` path does, since a locked pool usually needs a human to fix the unlock method,
+    /// not just time.
+    #[tracing::instrument(fields(name = ?self.name_any(), status = ?self.status) skip(self))]
+    async fn unlock_pool(&self) -> Result<Action, Error> {
+        let Some(encryption) = self.spec.encryption() else {
+            let message = format!(
+                "Pool '{}' is locked but the CR has no spec.encryption.unlock_method configured",
+                self.name_any()
+            );
+            self.k8s_notify("Unlock", "MissingUnlockMethod", &message, "Warning")
+                .await;
+            error!("{message}");
+            return Ok(Action::requeue(Duration::from_secs(self.ctx.interval)));
+        };
+
+        match self
+            .pools_api()
+            .put_node_pool_unlock(
+                &self.spec.node(),
+                &self.name_any(),
+                unlock_method_body(encryption),
+            )
+            .await
+        {
+            Ok(_) => {
+                self.k8s_notify("Unlock", "Unlocked", "Pool unlocked", "Normal")
+                    .await;
+            }
+            Err(error) => {
+                let message = format!(
+                    "Failed to unlock pool '{}' using {:?}: {error}",
+                    self.name_any(),
+                    encryption
+                );
+                self.k8s_notify("Unlock", "UnlockFailed", &message, "Warning")
+                    .await;
+                error!("{message}");
+            }
+        }
+
+        Ok(Action::requeue(Duration::from_secs(self.ctx.interval)))
+    }
+
     /// Gets pool from control plane and sets state as applicable.
     #[tracing::instrument(fields(name = ?self.name_any(), status = ?self.status) skip(self))]
     async fn pool_created(self) -> Result<Action, Error> {
-        let pool = self
-            .pools_api()
-            .get_node_pool(&self.spec.node(), &self.name_any())
-            .await?
-            .into_body();
+        let (response, slow) = match super::poll_timer::call_with_timer(
+            &self.name_any(),
+            "get_node_pool",
+            self.ctx.slow_call_threshold,
+            self.ctx.call_timeout,
+            self.pools_api()
+                .get_node_pool(&self.spec.node(), &self.name_any()),
+        )
+        .await
+        {
+            Ok(outcome) => outcome,
+            Err(_) => return Err(self.call_timed_out("get_node_pool").await),
+        };
+        if slow {
+            self.notify_slow_call("get_node_pool").await;
+        }
+        let pool = response?.into_body();
 
         if pool.state.is_some() {
             let _ = self.patch_status(DiskPoolStatus::from(pool)).await?;
@@ -427,11 +789,30 @@ impl ResourceContext {
     /// 'Unknown' and let the reconciler retry later.
     #[tracing::instrument(fields(name = ?self.name_any(), status = ?self.status) skip(self))]
     pub(crate) async fn pool_check(&self) -> Result<Action, Error> {
-        let pool = match self
-            .pools_api()
-            .get_node_pool(&self.spec.node(), &self.name_any())
-            .await
+        if self.spec.stopped() {
+            // Stopped pools aren't imported, so there's nothing on the node to check; don't
+            // requeue, `reconcile` re-enters us via `Self::create_or_import` once unstopped.
+            return Ok(Action::await_change());
+        }
+
+        let (call_result, slow) = match super::poll_timer::call_with_timer(
+            &self.name_any(),
+            "get_node_pool",
+            self.ctx.slow_call_threshold,
+            self.ctx.call_timeout,
+            self.pools_api()
+                .get_node_pool(&self.spec.node(), &self.name_any()),
+        )
+        .await
         {
+            Ok(outcome) => outcome,
+            Err(_) => return Err(self.call_timed_out("get_node_pool").await),
+        };
+        if slow {
+            self.notify_slow_call("get_node_pool").await;
+        }
+
+        let pool = match call_result {
             Ok(response) => response,
             Err(clients::tower::Error::Response(response)) => {
                 return if response.status() == clients::tower::StatusCode::NOT_FOUND {
@@ -450,10 +831,13 @@ impl ResourceContext {
 
                         // We expected the control plane to have a spec for this pool. It didn't so
                         // set the pool_status in CRD to None.
-                        self.mark_pool_not_found().await
+                        self.mark_pool_not_found(false).await
                     }
                 } else if response.status() == clients::tower::StatusCode::SERVICE_UNAVAILABLE || response.status() == clients::tower::StatusCode::REQUEST_TIMEOUT {
-                    // Probably grpc server is not yet up
+                    // Probably grpc server is not yet up; fail over to another endpoint (if one
+                    // is configured) and back off exponentially rather than hammering an
+                    // already-struggling control plane at a fixed cadence.
+                    self.ctx.http.mark_current_unhealthy();
                     self.k8s_notify(
                         "Unreachable",
                         "Check",
@@ -461,7 +845,7 @@ impl ResourceContext {
                         "Warning",
                     )
                         .await;
-                    self.mark_pool_not_found().await
+                    self.mark_pool_not_found(true).await
                 }
                 else {
                     self.k8s_notify(
@@ -475,8 +859,9 @@ impl ResourceContext {
                 }
             }
             Err(clients::tower::Error::Request(_)) => {
-                // Probably grpc server is not yet up
-                return self.mark_pool_not_found().await
+                // Probably grpc server is not yet up; fail over and back off exponentially.
+                self.ctx.http.mark_current_unhealthy();
+                return self.mark_pool_not_found(true).await
             }
         }.into_body();
         // As pool exists, set the status based on the presence of pool state.
@@ -486,6 +871,31 @@ impl ResourceContext {
     /// If the pool, has a state we set that status to the CR and if it does not have a state
     /// we set the status as unknown so that we can try again later.
     async fn set_status_or_unknown(&self, pool: Pool) -> Result<Action, Error> {
+        if self.spec.stopped() {
+            let _ = self.patch_status(DiskPoolStatus::stopped(&self.status)).await?;
+            return Ok(Action::await_change());
+        }
+
+        // Encrypted-but-not-yet-unlocked is reported as present, just not running, same as
+        // stratisd treats a locked pool; recognize that here and attempt the unlock, rather than
+        // only copying its status onto the CR in the `pool.state.is_some()` branch below.
+        if matches!(
+            pool.state.as_ref().map(|state| state.status),
+            Some(models::PoolStatus::Locked)
+        ) {
+            return self.unlock_pool().await;
+        }
+
+        if matches!(
+            pool.state.as_ref().map(|state| state.status),
+            Some(models::PoolStatus::Online)
+        ) {
+            // Back to a healthy steady state: drop the retry count so a later transient failure
+            // starts its backoff curve fresh instead of picking up where a past, unrelated one
+            // left off, and so polling settles back to the plain `interval`.
+            self.ctx.reset_retries(&self.name_any()).await;
+        }
+
         if pool.state.is_some() {
             if let Some(status) = &self.status {
                 let mut new_status = DiskPoolStatus::from(pool);
@@ -610,3 +1020,36 @@ impl ResourceContext {
         Ok(Action::await_change())
     }
 }
+
+/// Best-effort HTTP status code for an upstream RPC error, for attaching to [`Error::PoolRpcFailed`].
+/// Transport-level failures (connection refused, timeout, ...) have no status code to report.
+fn rpc_status_code(error: &clients::tower::Error<RestJsonError>) -> u16 {
+    match error {
+        clients::tower::Error::Response(response) => response.status().as_u16(),
+        clients::tower::Error::Request(_) => 0,
+    }
+}
+
+/// Maps the CR's `spec.encryption` block onto the wire shape `CreatePoolBody` expects, for
+/// `ResourceContext::create_or_import`.
+///
+/// `DiskPoolEncryption`/`UnlockMethod` belong on `v1beta1::DiskPoolSpec` (the CRD `self.spec`
+/// actually is, per `ResourceContext::inner: Arc<DiskPool>`), the same module `spec.node()`/
+/// `spec.disks()`/`spec.stopped()`/`spec.topology()` already come from just above - not on the
+/// legacy `MayastorPoolSpec`'s own, differently-shaped `encryption()`.
+fn encryption_body(encryption: &super::diskpool::v1beta1::DiskPoolEncryption) -> models::PoolEncryption {
+    models::PoolEncryption {
+        key_name: encryption.key_name().to_string(),
+        unlock_method: unlock_method_body(encryption),
+    }
+}
+
+/// Maps the CR's `spec.encryption.unlock_method` onto the wire shape the control plane's unlock
+/// call expects, for both [`encryption_body`] (create-time) and
+/// `ResourceContext::unlock_pool` (start-time).
+fn unlock_method_body(encryption: &super::diskpool::v1beta1::DiskPoolEncryption) -> models::UnlockMethod {
+    match encryption.unlock_method() {
+        super::diskpool::v1beta1::UnlockMethod::KeyRef(key) => models::UnlockMethod::KeyRef(key.clone()),
+        super::diskpool::v1beta1::UnlockMethod::Clevis => models::UnlockMethod::Clevis {},
+    }
+}
@@ -2,6 +2,16 @@ use kube::core::crd::MergeError;
 use openapi::{clients, models::RestJsonError};
 use snafu::Snafu;
 
+/// Identifies which CR and which node a pool-level error happened against, so the requeue
+/// `warn!` in `error_policy` can point at the offending resource instead of a bare "create
+/// failed". It is not written to the CR's status.
+#[derive(Debug, Clone)]
+pub struct PoolContext {
+    pub name: String,
+    pub namespace: String,
+    pub node: String,
+}
+
 /// Errors generated during the reconciliation loop
 #[derive(Debug, Snafu)]
 #[allow(clippy::enum_variant_names)]
@@ -35,6 +45,64 @@ pub enum Error {
         name: String,
         field: String,
     },
+    #[snafu(display(
+        "Pool '{}/{}' on node '{}': disk '{}' rejected: {}, retrying in {} seconds",
+        ctx.namespace, ctx.name, ctx.node, device, message, timeout
+    ))]
+    /// The io-engine (or the control plane acting on its behalf) rejected a specific disk device,
+    /// e.g. because it's already claimed by another pool or can't be found on the node.
+    PoolDeviceRejected {
+        ctx: PoolContext,
+        device: String,
+        message: String,
+        /// Exponential-backoff-with-jitter delay computed from the resource's `num_retries`, see
+        /// `OperatorContext::backoff_delay`.
+        timeout: u32,
+    },
+    #[snafu(display(
+        "Pool '{}/{}' on node '{}': upstream RPC failed with status {}: {}, retrying in {} seconds",
+        ctx.namespace, ctx.name, ctx.node, status, message, timeout
+    ))]
+    /// A pool create/import/check RPC to the control plane failed with a status code and message
+    /// that don't map to a more specific variant above.
+    PoolRpcFailed {
+        ctx: PoolContext,
+        status: u16,
+        message: String,
+        /// Exponential-backoff-with-jitter delay computed from the resource's `num_retries`, see
+        /// `OperatorContext::backoff_delay`.
+        timeout: u32,
+    },
+    #[snafu(display("Duplicate DiskPool CR, retrying in {} seconds", timeout))]
+    Duplicate {
+        timeout: u32,
+    },
+    #[snafu(display("Invalid DiskPool spec '{}': {}, retrying in {} seconds", name, message, timeout))]
+    SpecError {
+        name: String,
+        message: String,
+        timeout: u32,
+    },
+    #[snafu(display(
+        "Pool '{}/{}' on node '{}': control-plane call '{}' timed out, retrying in {} seconds",
+        ctx.namespace, ctx.name, ctx.node, call, timeout
+    ))]
+    /// A `pools_api()`/`block_devices_api()` call was still pending past the configured
+    /// `call_timeout` and was given up on; see `poll_timer::call_with_timer`.
+    CallTimedOut {
+        ctx: PoolContext,
+        call: &'static str,
+        /// Exponential-backoff-with-jitter delay computed from the resource's `num_retries`, see
+        /// `OperatorContext::backoff_delay`.
+        timeout: u32,
+    },
+    #[snafu(display("Pool '{}/{}': {}", ctx.namespace, ctx.name, reason))]
+    /// A reconcile step failed in a way that isn't worth retrying automatically (the caller
+    /// awaits an external change, e.g. a user fixing the CR, instead).
+    ReconcileError {
+        ctx: PoolContext,
+        reason: String,
+    },
 }
 
 impl From<clients::tower::Error<RestJsonError>> for Error {
@@ -0,0 +1,207 @@
+//! Persistent, retrying reconciliation job queue, modeled on pict-rs's `job_queue` table (a
+//! `queue`, a `job JSONB` payload, a `new`/`running` status, and a heartbeat column): one job per
+//! `DiskPool` CR name, so a crash mid-reconcile resumes the job on restart instead of losing it,
+//! the way the in-memory `ResourceContext::num_retries` counter would.
+//!
+//! Persisted as a `ConfigMap`, one JSON-encoded job per CR name in its `data` map (as opposed to
+//! a single combined blob, the way [`super::migration_journal::MigrationJournal`] is) so a single
+//! corrupted entry can be quarantined as an `InvalidJob` without losing every other queued job —
+//! there's no generic key-value store wired into the operator process (it only talks to the
+//! control plane over REST), so the `ConfigMap` the operator already has `kube::Client` access to
+//! stands in for the `job_queue` table.
+
+use crate::error::Error;
+use chrono::{DateTime, Utc};
+use kube::{
+    api::{Api, Patch, PatchParams},
+    Client,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tracing::warn;
+
+const QUEUE_CONFIG_MAP: &str = "dsp-operator-job-queue";
+const WHO_AM_I: &str = "DiskPool Operator";
+
+/// Matches `PoolState::Error`'s documented retry ceiling: once a job has been attempted this many
+/// times, `fail` reports it exhausted and removes it from the queue. The CR's status is not
+/// changed, and the next reconcile of the CR starts a fresh job at attempt 0.
+const MAX_ATTEMPTS: u32 = 10;
+/// Base back-off applied between retries, doubled per attempt and capped.
+const RETRY_BACKOFF_BASE: chrono::Duration = chrono::Duration::seconds(5);
+const RETRY_BACKOFF_MAX: chrono::Duration = chrono::Duration::minutes(10);
+/// A `running` job whose heartbeat is older than this is assumed to belong to a crashed operator
+/// process and is eligible to be dequeued again.
+const HEARTBEAT_TTL: chrono::Duration = chrono::Duration::minutes(2);
+
+/// A job's lifecycle state.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(tag = "status", rename_all = "camelCase")]
+enum JobStatus {
+    /// Queued, eligible for dequeue once `retry_after` (if any) has elapsed.
+    New,
+    /// Being worked on by some operator process, last seen alive at `heartbeat`.
+    Running { heartbeat: DateTime<Utc> },
+}
+
+/// A single queued reconciliation job, keyed by its CR name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Job {
+    status: JobStatus,
+    /// Number of attempts made so far.
+    attempt: u32,
+    /// Not eligible for dequeue until this time has passed (back-off after a failed attempt).
+    retry_after: Option<DateTime<Utc>>,
+    /// The error from the most recent failed attempt, if any, kept for diagnostics.
+    last_error: Option<String>,
+}
+
+impl Job {
+    fn new() -> Self {
+        Self {
+            status: JobStatus::New,
+            attempt: 0,
+            retry_after: None,
+            last_error: None,
+        }
+    }
+
+    /// Whether this job is eligible to be dequeued right now: brand new or past its retry
+    /// back-off, or `running` with an expired heartbeat (its owning process crashed).
+    fn due(&self) -> bool {
+        match &self.status {
+            JobStatus::New => self.retry_after.map(|at| Utc::now() >= at).unwrap_or(true),
+            JobStatus::Running { heartbeat } => Utc::now() >= *heartbeat + HEARTBEAT_TTL,
+        }
+    }
+}
+
+/// Outcome of reporting a failed attempt back to the queue.
+pub(crate) enum RetryOutcome {
+    /// Requeued with a back-off of `delay`; `attempt` is still below the retry ceiling.
+    Retry { attempt: u32, delay: chrono::Duration },
+    /// `attempt` has reached [`MAX_ATTEMPTS`]. The job is removed from the queue, so the next
+    /// `dequeue` for the same name starts a fresh job at attempt 0.
+    Exhausted { attempt: u32 },
+}
+
+/// The persistent job queue: one job per CR name.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct JobQueue {
+    jobs: BTreeMap<String, Job>,
+}
+
+impl JobQueue {
+    /// Load the queue from its `ConfigMap`, or start a fresh, empty one if it doesn't exist yet.
+    /// An entry whose JSON fails to deserialize is an `InvalidJob`: it's logged and dropped
+    /// (quarantined) rather than panicking the whole load over one corrupted record.
+    pub(crate) async fn load(k8s: &Client, namespace: &str) -> Result<Self, Error> {
+        let api: Api<k8s_openapi::api::core::v1::ConfigMap> = Api::namespaced(k8s.clone(), namespace);
+        let Some(cm) = api.get_opt(QUEUE_CONFIG_MAP).await? else {
+            return Ok(Self::default());
+        };
+        let mut jobs = BTreeMap::new();
+        for (name, json) in cm.data.unwrap_or_default() {
+            match serde_json::from_str::<Job>(&json) {
+                Ok(job) => {
+                    jobs.insert(name, job);
+                }
+                Err(source) => {
+                    warn!(%name, %source, "Discarding invalid job queue entry");
+                }
+            }
+        }
+        Ok(Self { jobs })
+    }
+
+    /// Persist the queue back to its `ConfigMap`, creating it on first use.
+    async fn save(&self, k8s: &Client, namespace: &str) -> Result<(), Error> {
+        let api: Api<k8s_openapi::api::core::v1::ConfigMap> = Api::namespaced(k8s.clone(), namespace);
+        let mut data = BTreeMap::new();
+        for (name, job) in &self.jobs {
+            let json = serde_json::to_string(job).map_err(|source| Error::Generic {
+                message: format!("Failed to serialise job queue entry '{name}': {source}"),
+            })?;
+            data.insert(name.clone(), json);
+        }
+        let cm = serde_json::json!({
+            "apiVersion": "v1",
+            "kind": "ConfigMap",
+            "metadata": {
+                "name": QUEUE_CONFIG_MAP,
+                "namespace": namespace,
+            },
+            "data": data,
+        });
+        api.patch(
+            QUEUE_CONFIG_MAP,
+            &PatchParams::apply(WHO_AM_I),
+            &Patch::Apply(&cm),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Dequeue the job for `name` if one is due (brand new, past its retry back-off, or a
+    /// `running` job whose heartbeat expired), transitioning it to `running`. Returns the attempt
+    /// number about to be made, or `None` if the job isn't due yet.
+    pub(crate) async fn dequeue(
+        &mut self,
+        k8s: &Client,
+        namespace: &str,
+        name: &str,
+    ) -> Result<Option<u32>, Error> {
+        let job = self.jobs.entry(name.to_string()).or_insert_with(Job::new);
+        if !job.due() {
+            return Ok(None);
+        }
+        job.status = JobStatus::Running { heartbeat: Utc::now() };
+        let attempt = job.attempt;
+        self.save(k8s, namespace).await?;
+        Ok(Some(attempt))
+    }
+
+    /// Record that `name`'s attempt completed successfully, removing its job from the queue.
+    pub(crate) async fn complete(
+        &mut self,
+        k8s: &Client,
+        namespace: &str,
+        name: &str,
+    ) -> Result<(), Error> {
+        if self.jobs.remove(name).is_some() {
+            self.save(k8s, namespace).await?;
+        }
+        Ok(())
+    }
+
+    /// Record that `name`'s attempt failed with `error`, requeuing it with an exponential
+    /// back-off unless it has now reached [`MAX_ATTEMPTS`], in which case the job is removed and
+    /// [`RetryOutcome::Exhausted`] is returned.
+    pub(crate) async fn fail(
+        &mut self,
+        k8s: &Client,
+        namespace: &str,
+        name: &str,
+        error: String,
+    ) -> Result<RetryOutcome, Error> {
+        let job = self.jobs.entry(name.to_string()).or_insert_with(Job::new);
+        job.attempt += 1;
+        job.last_error = Some(error);
+
+        let outcome = if job.attempt >= MAX_ATTEMPTS {
+            let attempt = job.attempt;
+            self.jobs.remove(name);
+            RetryOutcome::Exhausted { attempt }
+        } else {
+            let delay = (RETRY_BACKOFF_BASE * 2i32.saturating_pow(job.attempt)).min(RETRY_BACKOFF_MAX);
+            job.status = JobStatus::New;
+            job.retry_after = Some(Utc::now() + delay);
+            RetryOutcome::Retry {
+                attempt: job.attempt,
+                delay,
+            }
+        };
+        self.save(k8s, namespace).await?;
+        Ok(outcome)
+    }
+}
@@ -3,10 +3,15 @@
 //!
 //! Successfully created pools are recreated by the control plane.
 
+mod client_pool;
 pub(crate) mod context;
 mod diskpool;
 pub(crate) mod error;
+mod job_queue;
 mod mayastorpool;
+mod metrics;
+mod migration_journal;
+mod poll_timer;
 
 use crate::diskpool::client::{
     create_missing_cr, create_v1beta1_cr, discard_older_schema, migrate_to_v1beta1, v1beta1_api,
@@ -29,6 +34,7 @@ use kube::{
     Client, ResourceExt,
 };
 use mayastorpool::client::{check_crd, delete, list};
+use migration_journal::MigrationJournal;
 use openapi::clients::{self, tower::Url};
 use std::{collections::HashMap, sync::Arc, time::Duration};
 use tracing::{error, info, trace, warn};
@@ -40,7 +46,11 @@ const BACKOFF_PERIOD: u64 = 20;
 /// reconciliation loop
 fn error_policy(_object: Arc<DiskPool>, error: &Error, _ctx: Arc<OperatorContext>) -> Action {
     let duration = Duration::from_secs(match error {
-        Error::Duplicate { timeout } | Error::SpecError { timeout, .. } => (*timeout).into(),
+        Error::Duplicate { timeout }
+        | Error::SpecError { timeout, .. }
+        | Error::PoolDeviceRejected { timeout, .. }
+        | Error::PoolRpcFailed { timeout, .. }
+        | Error::CallTimedOut { timeout, .. } => (*timeout).into(),
 
         Error::ReconcileError { .. } => {
             return Action::await_change();
@@ -70,6 +80,22 @@ async fn reconcile(dsp: Arc<DiskPool>, ctx: Arc<OperatorContext>) -> Result<Acti
         return Ok(Action::await_change());
     };
 
+    // `spec.stopped` is the user's desired run-state, independent of `status.cr_state`: flipping
+    // it to `true` on an imported pool should stop it regardless of which state reconcile would
+    // otherwise have dispatched to, rather than waiting for the next naturally-occurring
+    // transition to notice.
+    if dsp.spec.stopped()
+        && !matches!(
+            dsp.status,
+            Some(DiskPoolStatus {
+                cr_state: CrPoolState::Stopped,
+                ..
+            })
+        )
+    {
+        return dsp.stop_pool().await;
+    }
+
     match dsp.status {
         Some(DiskPoolStatus {
             cr_state: CrPoolState::Creating,
@@ -82,7 +108,27 @@ async fn reconcile(dsp: Arc<DiskPool>, ctx: Arc<OperatorContext>) -> Result<Acti
         | Some(DiskPoolStatus {
             cr_state: CrPoolState::Terminating,
             ..
+        })
+        // Locked is re-checked the same way Created/Terminating are: `pool_check` is what
+        // notices the control plane reporting it unlocked again and retries the unlock while
+        // it doesn't.
+        | Some(DiskPoolStatus {
+            cr_state: CrPoolState::Locked,
+            ..
         }) => dsp.pool_check().await,
+        Some(DiskPoolStatus {
+            cr_state: CrPoolState::Stopped,
+            ..
+        }) => {
+            if dsp.spec.stopped() {
+                // Stays stopped; `pool_check` is what would normally requeue us periodically,
+                // and it's not called while stopped.
+                Ok(Action::await_change())
+            } else {
+                // Flipped back to `false`: re-run the same path a brand-new CR takes.
+                dsp.create_or_import().await
+            }
+        }
         // We use this state to indicate its a new CRD however, we could (and
         // perhaps should) use the finalizer callback.
         None => dsp.init_cr().await,
@@ -135,8 +181,14 @@ async fn pool_controller(args: ArgMatches) -> anyhow::Result<()> {
 
     let newdsp: Api<DiskPool> = v1beta1_api(&k8s, namespace);
 
-    let url = Url::parse(args.get_one::<String>("endpoint").unwrap())
-        .expect("endpoint is not a valid URL");
+    // A comma-separated list lets the operator fail over across control-plane replicas during a
+    // rolling restart instead of wedging every reconcile against one now-dead endpoint.
+    let endpoints: Vec<Url> = args
+        .get_one::<String>("endpoint")
+        .unwrap()
+        .split(',')
+        .map(|endpoint| Url::parse(endpoint.trim()).expect("endpoint is not a valid URL"))
+        .collect();
 
     let timeout: Duration = args
         .get_one::<String>("request-timeout")
@@ -145,29 +197,93 @@ async fn pool_controller(args: ArgMatches) -> anyhow::Result<()> {
         .expect("timeout value is invalid")
         .into();
 
-    let cfg = clients::tower::Configuration::new(url, timeout, None, None, true, None).map_err(
-        |error| {
+    let configs: Vec<(Url, clients::tower::Configuration)> = endpoints
+        .into_iter()
+        .map(|url| {
+            clients::tower::Configuration::new(url.clone(), timeout, None, None, true, None)
+                .map(|cfg| (url, cfg))
+        })
+        .collect::<Result<_, _>>()
+        .map_err(|error| {
             anyhow::anyhow!(
                 "Failed to create openapi configuration, Error: '{:?}'",
                 error
             )
-        },
-    )?;
+        })?;
+    // Used for the calls below that don't go through `ApiClientPool`: the one-off CR bootstrap
+    // and the periodic metrics refresh.
+    let cfg = configs[0].1.clone();
     let interval = args
         .get_one::<String>("interval")
         .unwrap()
         .parse::<humantime::Duration>()
         .expect("interval value is invalid")
         .as_secs();
+    let backoff_base: Duration = args
+        .get_one::<String>("backoff-base")
+        .unwrap()
+        .parse::<humantime::Duration>()
+        .expect("backoff-base value is invalid")
+        .into();
+    let backoff_max: Duration = args
+        .get_one::<String>("backoff-max")
+        .unwrap()
+        .parse::<humantime::Duration>()
+        .expect("backoff-max value is invalid")
+        .into();
+    let backoff_jitter = !args.get_flag("disable-backoff-jitter");
+    let slow_call_threshold: Duration = args
+        .get_one::<String>("slow-call-threshold")
+        .unwrap()
+        .parse::<humantime::Duration>()
+        .expect("slow-call-threshold value is invalid")
+        .into();
+    let call_timeout: Duration = args
+        .get_one::<String>("call-timeout")
+        .unwrap()
+        .parse::<humantime::Duration>()
+        .expect("call-timeout value is invalid")
+        .into();
+    let health_check_interval: Duration = args
+        .get_one::<String>("health-check-interval")
+        .unwrap()
+        .parse::<humantime::Duration>()
+        .expect("health-check-interval value is invalid")
+        .into();
+
+    let client_pool = Arc::new(client_pool::ApiClientPool::new(configs));
+    tokio::spawn({
+        let client_pool = client_pool.clone();
+        async move {
+            client_pool.health_check_periodically(health_check_interval).await;
+        }
+    });
+
     let context = OperatorContext::new(
         k8s.clone(),
         tokio::sync::RwLock::new(HashMap::new()),
-        clients::tower::ApiClient::new(cfg.clone()),
+        client_pool,
         interval,
+        backoff_base,
+        backoff_max,
+        backoff_jitter,
+        slow_call_threshold,
+        call_timeout,
     );
 
     create_missing_cr(&k8s, clients::tower::ApiClient::new(cfg.clone()), namespace).await?;
 
+    let metrics_bind_address: std::net::SocketAddr = args
+        .get_one::<String>("metrics-bind-address")
+        .unwrap()
+        .parse()
+        .expect("metrics-bind-address is not a valid socket address");
+    tokio::spawn(metrics::serve(metrics_bind_address));
+    tokio::spawn(metrics::refresh_pool_gauges_periodically(
+        clients::tower::ApiClient::new(cfg.clone()),
+        Duration::from_secs(interval),
+    ));
+
     info!(namespace, "Starting DiskPool Operator (dsp)");
 
     Controller::new(newdsp, watcher::Config::default())
@@ -223,7 +339,14 @@ async fn main() -> anyhow::Result<()> {
                 .short('e')
                 .env("ENDPOINT")
                 .default_value("http://ksnode-1:30011")
-                .help("an URL endpoint to the control plane's rest endpoint"),
+                .help("one or more (comma-separated) URL endpoints to the control plane's rest API, for health-checked failover across replicas"),
+        )
+        .arg(
+            Arg::new("health-check-interval")
+                .long("health-check-interval")
+                .env("HEALTH_CHECK_INTERVAL")
+                .default_value("10s")
+                .help("how often to probe every configured control-plane endpoint's health"),
         )
         .arg(
             Arg::new("namespace")
@@ -240,12 +363,54 @@ async fn main() -> anyhow::Result<()> {
                 .env("JAEGER_ENDPOINT")
                 .help("enable open telemetry and forward to jaeger"),
         )
+        .arg(
+            Arg::new("metrics-bind-address")
+                .long("metrics-bind-address")
+                .env("METRICS_BIND_ADDRESS")
+                .default_value("0.0.0.0:9502")
+                .help("address to serve the operator's Prometheus /metrics endpoint on"),
+        )
         .arg(
             Arg::new("disable-device-validation")
                 .long("disable-device-validation")
                 .action(clap::ArgAction::SetTrue)
                 .help("do not attempt to validate the block device prior to pool creation"),
         )
+        .arg(
+            Arg::new("backoff-base")
+                .long("backoff-base")
+                .env("BACKOFF_BASE")
+                .default_value("250ms")
+                .help("base delay for the exponential backoff applied to transient errors"),
+        )
+        .arg(
+            Arg::new("backoff-max")
+                .long("backoff-max")
+                .env("BACKOFF_MAX")
+                .default_value("5m")
+                .help("upper bound for the exponential backoff applied to transient errors"),
+        )
+        .arg(
+            Arg::new("disable-backoff-jitter")
+                .long("disable-backoff-jitter")
+                .env("DISABLE_BACKOFF_JITTER")
+                .action(clap::ArgAction::SetTrue)
+                .help("do not jitter the transient error backoff delay"),
+        )
+        .arg(
+            Arg::new("slow-call-threshold")
+                .long("slow-call-threshold")
+                .env("SLOW_CALL_THRESHOLD")
+                .default_value("5s")
+                .help("warn and raise a SlowControlPlane event once a control-plane call is pending longer than this"),
+        )
+        .arg(
+            Arg::new("call-timeout")
+                .long("call-timeout")
+                .env("CALL_TIMEOUT")
+                .default_value("30s")
+                .help("give up on a pending control-plane call after this long"),
+        )
         .get_matches();
 
     utils::print_package_info!();
@@ -278,31 +443,70 @@ fn normalize_disk(disk: &str) -> String {
 }
 
 /// Migrate from MayastorPool.
+///
+/// Progress is recorded in a [`MigrationJournal`] after each per-CR step so that a crash
+/// mid-migration resumes from where it left off on the next call, instead of silently dropping
+/// whichever CRs hadn't converted or been deleted yet. Items that failed are retried with
+/// back-off rather than attempted again every single call.
 pub(crate) async fn migrate_and_clean_msps(k8s: &Client, namespace: &str) -> Result<(), Error> {
     // Check if the MayastorPool CRD is present, and migrate from it if it is.
     match check_crd(k8s).await {
         // Fetch the MayastorPool CRs.
         Ok(true) => match list(k8s, namespace, PAGINATION_LIMIT).await {
             Ok(mut msps) => {
+                let mut journal = MigrationJournal::load(k8s, namespace).await?;
                 for msp in msps.iter_mut() {
                     let name = msp.clone().metadata.name.ok_or(Error::InvalidCRField {
                         field: "diskpool.metadata.name".to_string(),
                     })?;
-                    let node = msp.spec.node();
-                    let disks = msp.spec.disks();
-                    // Create the corresponding v1beta1 DiskPool CRs.
-                    if let Err(error) =
-                        create_v1beta1_cr(k8s, namespace, &name, DiskPoolSpec::new(node, disks, HashMap::new()))
-                            .await
-                    {
-                        error!("Migration failed for {name} with: {error:?}");
+
+                    if !journal.needs_attempt(&name) {
+                        continue;
+                    }
+
+                    // Create the corresponding v1beta1 DiskPool CR, unless a previous attempt
+                    // already got this far.
+                    if journal.needs_create(&name) {
+                        let node = msp.spec.node();
+                        let disks = msp.spec.disks();
+                        match create_v1beta1_cr(
+                            k8s,
+                            namespace,
+                            &name,
+                            DiskPoolSpec::new(node, disks, HashMap::new()),
+                        )
+                        .await
+                        {
+                            Ok(_) => journal.mark_converted(k8s, namespace, &name).await?,
+                            Err(error) => {
+                                error!("Migration failed for {name} with: {error:?}");
+                                journal
+                                    .mark_failed(k8s, namespace, &name, format!("{error:?}"))
+                                    .await?;
+                                continue;
+                            }
+                        }
                     }
+
                     // Patch the finalizers and delete the MayastorPool CRs.
-                    if let Err(error) = delete(k8s, namespace, msp).await {
-                        error!("Deletion failed for {name}  with: {error:?}");
+                    match delete(k8s, namespace, msp).await {
+                        Ok(_) => journal.mark_deleted(k8s, namespace, &name).await?,
+                        Err(error) => {
+                            error!("Deletion failed for {name}  with: {error:?}");
+                            journal
+                                .mark_failed(k8s, namespace, &name, format!("{error:?}"))
+                                .await?;
+                        }
                     }
                 }
                 info!("Migration and Cleanup of CRs from MayastorPool to DiskPool complete");
+                for (name, record) in journal.records() {
+                    if let migration_journal::MigrationState::Failed { reason, attempts, .. } =
+                        &record.state
+                    {
+                        warn!(pool = %name, attempts, reason, "MayastorPool migration still failing, will retry with backoff");
+                    }
+                }
             }
             Err(error) => {
                 return Err(Error::Generic {
@@ -18,7 +18,8 @@ printcolumn = r#"{ "name":"node", "type":"string", "description":"node the pool
 printcolumn = r#"{ "name":"status", "type":"string", "description":"pool status", "jsonPath":".status.state"}"#,
 printcolumn = r#"{ "name":"capacity", "type":"integer", "format": "int64", "minimum" : "0", "description":"total bytes", "jsonPath":".status.capacity"}"#,
 printcolumn = r#"{ "name":"used", "type":"integer", "format": "int64", "minimum" : "0", "description":"used bytes", "jsonPath":".status.used"}"#,
-printcolumn = r#"{ "name":"available", "type":"integer", "format": "int64", "minimum" : "0", "description":"available bytes", "jsonPath":".status.available"}"#
+printcolumn = r#"{ "name":"available", "type":"integer", "format": "int64", "minimum" : "0", "description":"available bytes", "jsonPath":".status.available"}"#,
+printcolumn = r#"{ "name":"encrypted", "type":"boolean", "description":"whether the pool is encrypted at rest", "jsonPath":".status.encrypted"}"#
 )]
 /// The pool spec which contains the parameters we use when creating the pool.
 pub(crate) struct MayastorPoolSpec {
@@ -26,6 +27,10 @@ pub(crate) struct MayastorPoolSpec {
     node: String,
     /// The disk device the pool is located on.
     disks: Vec<String>,
+    /// Encrypt the pool's devices at rest, keyed from a Kubernetes `Secret` rather than an
+    /// inline key. Absent means plaintext, so existing CRs (created before this field existed)
+    /// deserialize unaffected.
+    encryption: Option<MayastorPoolEncryption>,
 }
 
 impl MayastorPoolSpec {
@@ -37,6 +42,26 @@ impl MayastorPoolSpec {
     pub(crate) fn disks(&self) -> Vec<String> {
         self.disks.clone()
     }
+    /// Encrypt the pool's devices at rest with the given key source, if set.
+    pub(crate) fn encryption(&self) -> Option<&MayastorPoolEncryption> {
+        self.encryption.as_ref()
+    }
+}
+
+/// Where the key material for an encrypted `MayastorPool` comes from: a reference to a
+/// Kubernetes `Secret` name, rather than an inline key in the spec.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub(crate) struct MayastorPoolEncryption {
+    /// Name of the `Secret` (in the same namespace as the `MayastorPool`) holding the passphrase
+    /// used to bind the pool's key.
+    key_secret: String,
+}
+
+impl MayastorPoolEncryption {
+    /// Name of the `Secret` holding the passphrase used to bind the pool's key.
+    pub(crate) fn key_secret(&self) -> &str {
+        &self.key_secret
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
@@ -72,4 +97,8 @@ pub(crate) struct MayastorPoolStatus {
     used: u64,
     /// Available number of bytes.
     available: u64,
+    /// Whether the pool was provisioned with encryption at rest. Kept separate from `state` so
+    /// it stays visible once an initially-`Locked` pool transitions to `Online` after unlock.
+    #[serde(default)]
+    encrypted: bool,
 }
@@ -0,0 +1,150 @@
+//! Prometheus metrics for the DiskPool operator, derived from the `Pool`s (spec + state) the
+//! control plane's REST API reports. Refreshed on the same `interval` timer that drives
+//! reconciliation, so the gauges stay as fresh as the CRs themselves.
+
+use once_cell::sync::Lazy;
+use openapi::{clients, models};
+use prometheus::{register_int_gauge_vec, IntGaugeVec, TextEncoder};
+use std::time::Duration;
+use tracing::error;
+
+/// Per-pool capacity/commitment gauges, labelled by pool id, node, and whether a spec exists
+/// (`"true"` for a managed pool, `"false"` for one only discovered via its runtime state).
+static POOL_CAPACITY_BYTES: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "dsp_pool_capacity_bytes",
+        "Pool capacity in bytes",
+        &["pool", "node", "managed"]
+    )
+    .expect("metric can be registered")
+});
+static POOL_USED_BYTES: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "dsp_pool_used_bytes",
+        "Pool used bytes",
+        &["pool", "node", "managed"]
+    )
+    .expect("metric can be registered")
+});
+static POOL_COMMITTED_BYTES: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "dsp_pool_committed_bytes",
+        "Pool committed (replica accrued) bytes",
+        &["pool", "node", "managed"]
+    )
+    .expect("metric can be registered")
+});
+/// The `PoolStatus` ordinal, using the same `online > degraded > unknown/faulted` ordering the
+/// transport type's `PartialOrd` impl encodes, so dashboards can alert on a drop in this gauge.
+static POOL_STATUS_ORDINAL: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "dsp_pool_status",
+        "Pool status ordinal (higher is healthier; see PoolStatus::partial_cmp)",
+        &["pool", "node", "managed"]
+    )
+    .expect("metric can be registered")
+});
+/// Thin-provisioning over-commit ratio, `committed / capacity`, as a float (1.0 == fully
+/// committed, >1.0 == over-committed).
+static POOL_OVERCOMMIT_RATIO: Lazy<prometheus::GaugeVec> = Lazy::new(|| {
+    prometheus::register_gauge_vec!(
+        "dsp_pool_overcommit_ratio",
+        "Thin-provisioning overcommit ratio (committed/capacity)",
+        &["pool", "node", "managed"]
+    )
+    .expect("metric can be registered")
+});
+
+/// Ordinal used for [`POOL_STATUS_ORDINAL`], consistent with `PoolStatus`'s documented ordering
+/// (`online > degraded > unknown/faulted`); `Stopped`/`Locked` are ranked alongside the unhealthy
+/// states, since both mean the pool isn't currently serving I/O.
+fn status_ordinal(status: &models::PoolStatus) -> i64 {
+    match status {
+        models::PoolStatus::Online => 3,
+        models::PoolStatus::Degraded => 2,
+        models::PoolStatus::Unknown => 1,
+        models::PoolStatus::Faulted => 0,
+        models::PoolStatus::Stopped => 0,
+        models::PoolStatus::Locked => 0,
+    }
+}
+
+/// Encode all registered metrics in the Prometheus text exposition format.
+fn encode() -> String {
+    let metric_families = prometheus::gather();
+    let mut buffer = String::new();
+    if let Err(error) = TextEncoder::new().encode_utf8(&metric_families, &mut buffer) {
+        error!(%error, "Failed to encode DiskPool operator metrics");
+    }
+    buffer
+}
+
+/// Serve the operator metrics on a `/metrics` HTTP endpoint at the given address.
+pub async fn serve(addr: std::net::SocketAddr) {
+    use hyper::{server::conn::http1, service::service_fn, Request, Response};
+    use hyper_util::rt::TokioIo;
+    use tokio::net::TcpListener;
+
+    let listener = match TcpListener::bind(addr).await {
+        Ok(listener) => listener,
+        Err(error) => {
+            error!(%error, %addr, "Failed to bind DiskPool operator metrics listener");
+            return;
+        }
+    };
+    loop {
+        let (stream, _) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(error) => {
+                error!(%error, "Failed to accept DiskPool operator metrics connection");
+                continue;
+            }
+        };
+        tokio::task::spawn(async move {
+            let io = TokioIo::new(stream);
+            let service = service_fn(|_req: Request<hyper::body::Incoming>| async move {
+                Ok::<_, std::convert::Infallible>(Response::new(encode()))
+            });
+            if let Err(error) = http1::Builder::new().serve_connection(io, service).await {
+                error!(%error, "DiskPool operator metrics connection error");
+            }
+        });
+    }
+}
+
+/// Periodically refresh the per-pool gauges from a `get_pools` poll of the control plane's REST
+/// API, on the same cadence as the reconcile loop's `interval`.
+pub async fn refresh_pool_gauges_periodically(http: clients::tower::ApiClient, period: Duration) {
+    let mut interval = tokio::time::interval(period);
+    loop {
+        interval.tick().await;
+        match http.pools_api().get_pools().await {
+            Ok(response) => {
+                for pool in response.into_body() {
+                    let Some(state) = pool.state else { continue };
+                    let managed = if pool.spec.is_some() { "true" } else { "false" };
+                    let labels: [&str; 3] = [pool.id.as_str(), state.node.as_str(), managed];
+
+                    POOL_CAPACITY_BYTES
+                        .with_label_values(&labels)
+                        .set(state.capacity as i64);
+                    POOL_USED_BYTES.with_label_values(&labels).set(state.used as i64);
+                    let committed = state.committed.unwrap_or(0);
+                    POOL_COMMITTED_BYTES
+                        .with_label_values(&labels)
+                        .set(committed as i64);
+                    POOL_STATUS_ORDINAL
+                        .with_label_values(&labels)
+                        .set(status_ordinal(&state.status));
+                    let overcommit = if state.capacity == 0 {
+                        0.0
+                    } else {
+                        committed as f64 / state.capacity as f64
+                    };
+                    POOL_OVERCOMMIT_RATIO.with_label_values(&labels).set(overcommit);
+                }
+            }
+            Err(error) => error!(%error, "Failed to refresh DiskPool operator metrics"),
+        }
+    }
+}
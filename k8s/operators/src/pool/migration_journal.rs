@@ -0,0 +1,194 @@
+//! Durable record of the MayastorPool -> DiskPool migration, so a crash mid-migration can be
+//! resumed rather than leaving a half-converted cluster with no record of what succeeded.
+//!
+//! Persisted as a single `ConfigMap`, one JSON-encoded entry per source MayastorPool CR name.
+//! There's no generic key-value store wired into the operator process (it only talks to the
+//! control plane over REST), so the `ConfigMap` the operator already has `kube::Client` access to
+//! stands in for one.
+
+use crate::error::Error;
+use chrono::{DateTime, Utc};
+use kube::{
+    api::{Api, Patch, PatchParams},
+    Client,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+const JOURNAL_CONFIG_MAP: &str = "dsp-operator-migration-journal";
+const WHO_AM_I: &str = "DiskPool Operator";
+
+/// Base back-off applied between retries of a failed migration, doubled per attempt and capped.
+const RETRY_BACKOFF_BASE: chrono::Duration = chrono::Duration::seconds(10);
+const RETRY_BACKOFF_MAX: chrono::Duration = chrono::Duration::minutes(10);
+
+/// State of a single source CR's migration.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(tag = "state", rename_all = "camelCase")]
+pub(crate) enum MigrationState {
+    /// Not attempted yet, or attempted and still outstanding.
+    Pending,
+    /// The `DiskPool` CR has been created; the source `MayastorPool` CR still needs deleting.
+    Converted,
+    /// The `DiskPool` CR was created and the source `MayastorPool` CR was deleted. Terminal.
+    Deleted,
+    /// The last attempt failed with `reason`; eligible for retry once its back-off elapses.
+    Failed { reason: String, attempts: u32, last_attempt: DateTime<Utc> },
+}
+
+/// Durable, per-CR migration record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct MigrationRecord {
+    pub(crate) state: MigrationState,
+}
+
+impl MigrationRecord {
+    fn pending() -> Self {
+        Self {
+            state: MigrationState::Pending,
+        }
+    }
+
+    /// Whether this record still needs work: never attempted, partially converted, or failed and
+    /// past its retry back-off.
+    fn needs_attempt(&self) -> bool {
+        match &self.state {
+            MigrationState::Pending | MigrationState::Converted => true,
+            MigrationState::Deleted => false,
+            MigrationState::Failed {
+                attempts,
+                last_attempt,
+                ..
+            } => {
+                let backoff = (RETRY_BACKOFF_BASE * 2i32.saturating_pow(*attempts))
+                    .min(RETRY_BACKOFF_MAX);
+                Utc::now() >= *last_attempt + backoff
+            }
+        }
+    }
+}
+
+/// The full migration journal: one record per source `MayastorPool` CR name.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct MigrationJournal {
+    records: BTreeMap<String, MigrationRecord>,
+}
+
+impl MigrationJournal {
+    /// Load the journal from its `ConfigMap`, or start a fresh, empty one if it doesn't exist
+    /// yet.
+    pub(crate) async fn load(k8s: &Client, namespace: &str) -> Result<Self, Error> {
+        let api: Api<k8s_openapi::api::core::v1::ConfigMap> = Api::namespaced(k8s.clone(), namespace);
+        match api.get_opt(JOURNAL_CONFIG_MAP).await? {
+            Some(cm) => {
+                let data = cm.data.and_then(|mut d| d.remove("journal"));
+                match data {
+                    Some(json) => Ok(serde_json::from_str(&json).unwrap_or_default()),
+                    None => Ok(Self::default()),
+                }
+            }
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Persist the journal back to its `ConfigMap`, creating it on first use.
+    async fn save(&self, k8s: &Client, namespace: &str) -> Result<(), Error> {
+        let api: Api<k8s_openapi::api::core::v1::ConfigMap> = Api::namespaced(k8s.clone(), namespace);
+        let json = serde_json::to_string(self).map_err(|source| Error::Generic {
+            message: format!("Failed to serialise migration journal: {source}"),
+        })?;
+        let mut data = BTreeMap::new();
+        data.insert("journal".to_string(), json);
+        let cm = serde_json::json!({
+            "apiVersion": "v1",
+            "kind": "ConfigMap",
+            "metadata": {
+                "name": JOURNAL_CONFIG_MAP,
+                "namespace": namespace,
+            },
+            "data": data,
+        });
+        api.patch(
+            JOURNAL_CONFIG_MAP,
+            &PatchParams::apply(WHO_AM_I),
+            &Patch::Apply(&cm),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Whether `name` still needs a migration attempt (never tried, half-converted, or failed
+    /// and past its retry back-off).
+    pub(crate) fn needs_attempt(&self, name: &str) -> bool {
+        self.records
+            .get(name)
+            .map(MigrationRecord::needs_attempt)
+            .unwrap_or(true)
+    }
+
+    /// Whether the `DiskPool` CR for `name` still needs creating, i.e. it hasn't converted yet.
+    pub(crate) fn needs_create(&self, name: &str) -> bool {
+        !matches!(
+            self.records.get(name).map(|r| &r.state),
+            Some(MigrationState::Converted | MigrationState::Deleted)
+        )
+    }
+
+    /// Record that `name`'s `DiskPool` CR was created.
+    pub(crate) async fn mark_converted(
+        &mut self,
+        k8s: &Client,
+        namespace: &str,
+        name: &str,
+    ) -> Result<(), Error> {
+        self.records
+            .entry(name.to_string())
+            .or_insert_with(MigrationRecord::pending)
+            .state = MigrationState::Converted;
+        self.save(k8s, namespace).await
+    }
+
+    /// Record that `name`'s source `MayastorPool` CR was deleted; migration is complete.
+    pub(crate) async fn mark_deleted(
+        &mut self,
+        k8s: &Client,
+        namespace: &str,
+        name: &str,
+    ) -> Result<(), Error> {
+        self.records
+            .entry(name.to_string())
+            .or_insert_with(MigrationRecord::pending)
+            .state = MigrationState::Deleted;
+        self.save(k8s, namespace).await
+    }
+
+    /// Record that `name`'s migration attempt failed with `reason`, bumping its retry count.
+    pub(crate) async fn mark_failed(
+        &mut self,
+        k8s: &Client,
+        namespace: &str,
+        name: &str,
+        reason: String,
+    ) -> Result<(), Error> {
+        let record = self
+            .records
+            .entry(name.to_string())
+            .or_insert_with(MigrationRecord::pending);
+        let attempts = match &record.state {
+            MigrationState::Failed { attempts, .. } => attempts + 1,
+            _ => 1,
+        };
+        record.state = MigrationState::Failed {
+            reason,
+            attempts,
+            last_attempt: Utc::now(),
+        };
+        self.save(k8s, namespace).await
+    }
+
+    /// All records, for an admin to query which pools still need migration and why individual
+    /// conversions failed.
+    pub(crate) fn records(&self) -> &BTreeMap<String, MigrationRecord> {
+        &self.records
+    }
+}
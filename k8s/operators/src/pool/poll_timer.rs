@@ -0,0 +1,33 @@
+//! A lightweight wrapper around control-plane REST calls that surfaces stalls instead of letting
+//! them disappear into a silent `.await`, in the spirit of pict-rs's `WithPollTimer`: once a call
+//! has been pending longer than `threshold` it's logged, and once it's been pending longer than
+//! `timeout` it's given up on entirely, so a hung REST/gRPC server turns into a backoff-able
+//! error rather than blocking the reconcile loop indefinitely.
+
+use std::{future::Future, time::Duration};
+use tokio::time::error::Elapsed;
+use tracing::warn;
+
+/// Await `fut` (a named control-plane `call` against `resource`), warning once it's been pending
+/// longer than `threshold`, and giving up with `Err(Elapsed)` once it's been pending longer than
+/// `timeout`.
+///
+/// Returns `(output, slow)` on success, where `slow` is `true` if the `threshold` warning fired,
+/// so the caller can decide whether to also raise a throttled `k8s_notify` event for it.
+pub(crate) async fn call_with_timer<T>(
+    resource: &str,
+    call: &'static str,
+    threshold: Duration,
+    timeout: Duration,
+    fut: impl Future<Output = T>,
+) -> Result<(T, bool), Elapsed> {
+    tokio::pin!(fut);
+    match tokio::time::timeout(threshold, &mut fut).await {
+        Ok(output) => Ok((output, false)),
+        Err(_) => {
+            warn!(resource, call, ?threshold, "Control-plane call pending longer than threshold, still waiting");
+            let output = tokio::time::timeout(timeout.saturating_sub(threshold), fut).await?;
+            Ok((output, true))
+        }
+    }
+}
@@ -14,6 +14,8 @@ pub enum Error {
     InvalidUri {
         source: hyper::http::uri::InvalidUri,
     },
+    #[error("Forward disconnected, reconnecting (attempt {attempts}): {source}")]
+    Reconnecting { attempts: u32, source: kube_forward::Error },
 }
 
 impl From<kube_forward::Error> for Error {
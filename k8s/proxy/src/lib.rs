@@ -6,32 +6,33 @@ use std::path::PathBuf;
 
 mod error;
 mod proxy;
+mod supervisor;
 
 /// A [`error::Error`].
 pub use error::Error;
-use kube::config::KubeConfigOptions;
 /// OpenApi client helpers.
-pub use proxy::{ConfigBuilder, ForwardingProxy, LokiClient, Scheme};
+pub use proxy::{ClientManager, ConfigBuilder, ForwardingProxy, LokiClient, Scheme};
+/// Port-forward that can be re-established in place.
+pub use supervisor::{ForwardStatus, SupervisedForward};
 
 /// Get the `kube::Config` from the given kubeconfig file, or the default.
+///
+/// NOTE: Kubeconfig file may hold multiple contexts to communicate with different kubernetes
+/// clusters. We have to pick master address of current-context config only.
 pub async fn config_from_kubeconfig(
     kube_config_path: Option<PathBuf>,
 ) -> anyhow::Result<kube::Config> {
-    let mut config = match kube_config_path {
-        Some(config_path) => {
-            // NOTE: Kubeconfig file may hold multiple contexts to communicate
-            //       with different kubernetes clusters. We have to pick master
-            //       address of current-context config only
-            let kube_config = kube::config::Kubeconfig::read_from(&config_path)?;
-            kube::Config::from_custom_kubeconfig(kube_config, &Default::default()).await?
-        }
-        None => kube::Config::from_kubeconfig(&KubeConfigOptions::default()).await?,
-    };
-    config.apply_debug_overrides();
-    Ok(config)
+    ConfigBuilder::new()
+        .with_kube_config(kube_config_path)
+        .build()
+        .await
 }
 
 /// Get the `kube::Client` from the given kubeconfig file, or the default.
+///
+/// This builds a brand-new client (and TLS stack) on every call; callers that hit the API server
+/// repeatedly, such as a reconciler or REST handlers, should prefer a pooled
+/// [`ForwardingProxy`] instead.
 pub async fn client_from_kubeconfig(
     kube_config_path: Option<PathBuf>,
 ) -> anyhow::Result<kube::Client> {
@@ -0,0 +1,295 @@
+use crate::error::Error;
+use kube::config::{KubeConfigOptions, Kubeconfig};
+use std::{
+    collections::VecDeque,
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::sync::Mutex;
+
+/// Default bound on how many idle `kube::Client`s a `ClientManager` keeps pooled for reuse.
+const DEFAULT_MAX_SIZE: usize = 10;
+/// Default idle timeout after which a pooled client is evicted and rebuilt on next checkout.
+const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Addressing scheme used when building a forwarded connection's base url.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scheme {
+    /// Plain HTTP.
+    Http,
+    /// TLS-secured HTTPS.
+    Https,
+}
+
+impl Scheme {
+    /// The scheme's string representation, as used in a url.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scheme::Http => "http",
+            Scheme::Https => "https",
+        }
+    }
+}
+
+/// Builds a `kube::Config` from a kubeconfig file, or the default search path, applying the
+/// usual debug overrides along the way.
+#[derive(Debug, Default)]
+pub struct ConfigBuilder {
+    kube_config_path: Option<PathBuf>,
+}
+
+impl ConfigBuilder {
+    /// New, empty builder that resolves the default kubeconfig unless told otherwise.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Use the given kubeconfig file rather than the default search path.
+    pub fn with_kube_config(mut self, kube_config_path: Option<PathBuf>) -> Self {
+        self.kube_config_path = kube_config_path;
+        self
+    }
+    /// Resolve the `kube::Config`.
+    pub async fn build(self) -> anyhow::Result<kube::Config> {
+        let mut config = match self.kube_config_path {
+            Some(config_path) => {
+                let kube_config = Kubeconfig::read_from(&config_path)?;
+                kube::Config::from_custom_kubeconfig(kube_config, &Default::default()).await?
+            }
+            None => kube::Config::from_kubeconfig(&KubeConfigOptions::default()).await?,
+        };
+        config.apply_debug_overrides();
+        Ok(config)
+    }
+}
+
+struct Idle {
+    client: kube::Client,
+    since: Instant,
+}
+
+/// A bb8/r2d2-style pool of `kube::Client`s built from a single resolved `kube::Config`.
+///
+/// A `kube::Client` is already cheap to clone (its transport stack is reference-counted), so
+/// "checking out" a client is mostly just handing back a clone; what the pool actually buys is
+/// reuse of up to `max_size` idle clients, plus a health-check-on-checkout: a pooled client idle
+/// for longer than `idle_timeout` is discarded, any other pooled client is re-validated with a
+/// cheap API call, and a fresh one is built from `config` when none passes. The number of clients
+/// checked out at once is not limited; `max_size` bounds only the idle clients kept for reuse.
+pub struct ClientManager {
+    config: kube::Config,
+    max_size: usize,
+    idle_timeout: Duration,
+    idle: Mutex<VecDeque<Idle>>,
+}
+
+impl ClientManager {
+    /// New manager bound to `config`, keeping at most `max_size` clients pooled and discarding
+    /// ones that have sat idle for longer than `idle_timeout`.
+    pub fn new(config: kube::Config, max_size: usize, idle_timeout: Duration) -> Self {
+        Self {
+            config,
+            max_size,
+            idle_timeout,
+            idle: Mutex::new(VecDeque::with_capacity(max_size)),
+        }
+    }
+
+    /// Check out a client: reuse a pooled, healthy, not-too-idle one if available, otherwise
+    /// build a fresh one from `config`.
+    pub async fn checkout(&self) -> Result<kube::Client, Error> {
+        let mut idle = self.idle.lock().await;
+        while let Some(candidate) = idle.pop_front() {
+            if candidate.since.elapsed() > self.idle_timeout {
+                continue;
+            }
+            if Self::is_healthy(&candidate.client).await {
+                return Ok(candidate.client);
+            }
+        }
+        drop(idle);
+        Ok(kube::Client::try_from(self.config.clone())?)
+    }
+
+    /// Return a client to the pool once the caller is done with it, provided the pool isn't
+    /// already at `max_size`. Dropping the client instead is also fine, it just won't be reused.
+    pub async fn checkin(&self, client: kube::Client) {
+        let mut idle = self.idle.lock().await;
+        if idle.len() < self.max_size {
+            idle.push_back(Idle {
+                client,
+                since: Instant::now(),
+            });
+        }
+    }
+
+    /// Cheap API discovery call used as the health-check-on-checkout: fails fast if the
+    /// token/cert backing `client` is no longer valid.
+    async fn is_healthy(client: &kube::Client) -> bool {
+        client.apiserver_version().await.is_ok()
+    }
+}
+
+/// Hands out pooled, cheaply-cloned `kube::Client`s to reconcilers and REST handlers that hit the
+/// API server repeatedly, removing per-request TLS setup cost under reconcile storms. It does not
+/// limit how many clients are checked out at once.
+#[derive(Clone)]
+pub struct ForwardingProxy {
+    manager: Arc<ClientManager>,
+}
+
+impl ForwardingProxy {
+    /// Build a proxy backed by a fresh `ClientManager`, resolving the config via `builder`.
+    pub async fn new(
+        builder: ConfigBuilder,
+        max_size: usize,
+        idle_timeout: Duration,
+    ) -> anyhow::Result<Self> {
+        let config = builder.build().await?;
+        Ok(Self {
+            manager: Arc::new(ClientManager::new(config, max_size, idle_timeout)),
+        })
+    }
+
+    /// Build a proxy from the default kubeconfig, using the default pool bounds.
+    pub async fn try_default() -> anyhow::Result<Self> {
+        Self::new(ConfigBuilder::new(), DEFAULT_MAX_SIZE, DEFAULT_IDLE_TIMEOUT).await
+    }
+
+    /// Check out a pooled client. Callers should `release` it back once done so it can be
+    /// reused; dropping it instead is harmless, it just won't be pooled.
+    pub async fn client(&self) -> Result<kube::Client, Error> {
+        self.manager.checkout().await
+    }
+
+    /// Return a client to the pool for reuse by the next caller.
+    pub async fn release(&self, client: kube::Client) {
+        self.manager.checkin(client).await;
+    }
+}
+
+/// A single log line returned by a Loki query, along with the stream labels it was tagged with.
+#[derive(Debug, Clone)]
+pub struct LokiEntry {
+    /// Unix nanosecond timestamp, as reported by Loki.
+    pub timestamp_ns: i64,
+    /// The raw log line.
+    pub line: String,
+    /// Stream labels (container, pod, etc.) the line was tagged with.
+    pub labels: std::collections::HashMap<String, String>,
+}
+
+/// Minimal client for the Loki `/loki/api/v1/query_range` API, scoped to what the pool and
+/// reconciliation log query routes need: a LogQL selector plus a time range or a tail poll.
+#[derive(Debug, Clone)]
+pub struct LokiClient {
+    base_url: url::Url,
+    client: hyper_util::client::legacy::Client<
+        hyper_util::client::legacy::connect::HttpConnector,
+        String,
+    >,
+}
+
+impl LokiClient {
+    /// New client pointed at `base_url`, e.g. `http://loki.mayastor.svc:3100`.
+    pub fn new(base_url: url::Url) -> Self {
+        Self {
+            base_url,
+            client: hyper_util::client::legacy::Client::builder(hyper_util::rt::TokioExecutor::new())
+                .build_http(),
+        }
+    }
+
+    /// Build a client querying Loki through `forward`'s stable local url,
+    /// for callers that can't resolve Loki's in-cluster DNS name directly (eg a CLI running
+    /// outside the cluster) and so need a `Loki` service reached via port-forward.
+    pub async fn from_forward(forward: &crate::supervisor::SupervisedForward) -> Result<Self, Error> {
+        let uri = forward.uri().await?;
+        let base_url = url::Url::parse(&uri.to_string())?;
+        Ok(Self::new(base_url))
+    }
+
+    /// The Loki base url this client queries.
+    pub fn base_url(&self) -> &url::Url {
+        &self.base_url
+    }
+
+    /// Query lines matching `selector` within `[start_ns, end_ns]`, newest first, capped at
+    /// `limit`.
+    pub async fn query_range(
+        &self,
+        selector: &str,
+        start_ns: i64,
+        end_ns: i64,
+        limit: u32,
+    ) -> Result<Vec<LokiEntry>, Error> {
+        let mut url = self.base_url.join("/loki/api/v1/query_range")?;
+        url.query_pairs_mut()
+            .append_pair("query", selector)
+            .append_pair("start", &start_ns.to_string())
+            .append_pair("end", &end_ns.to_string())
+            .append_pair("limit", &limit.to_string())
+            .append_pair("direction", "backward");
+        self.get_entries(url).await
+    }
+
+    /// Poll once for lines matching `selector` that arrived after `since_ns`. Callers wanting a
+    /// continuous tail should call this repeatedly, advancing `since_ns` to the last entry seen.
+    pub async fn tail_once(&self, selector: &str, since_ns: i64) -> Result<Vec<LokiEntry>, Error> {
+        let now_ns = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_nanos() as i64)
+            .unwrap_or(i64::MAX);
+        // Loki's `start` is inclusive, so skip the line at `since_ns` that was already seen.
+        self.query_range(selector, since_ns + 1, now_ns, 1000).await
+    }
+
+    async fn get_entries(&self, url: url::Url) -> Result<Vec<LokiEntry>, Error> {
+        let uri: hyper::Uri = url.as_str().parse()?;
+        let response = self
+            .client
+            .get(uri)
+            .await
+            .map_err(|source| Error::AnyHow {
+                source: anyhow::anyhow!(source),
+            })?;
+        let body = http_body_util::BodyExt::collect(response.into_body())
+            .await
+            .map_err(|source| Error::AnyHow {
+                source: anyhow::anyhow!(source),
+            })?
+            .to_bytes();
+        let parsed: LokiQueryResponse = serde_json::from_slice(&body).map_err(|source| Error::AnyHow {
+            source: anyhow::anyhow!(source),
+        })?;
+        Ok(parsed
+            .data
+            .result
+            .into_iter()
+            .flat_map(|stream| {
+                let labels = stream.stream;
+                stream.values.into_iter().filter_map(move |[ts, line]| {
+                    Some(LokiEntry {
+                        timestamp_ns: ts.parse().ok()?,
+                        line,
+                        labels: labels.clone(),
+                    })
+                })
+            })
+            .collect())
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct LokiQueryResponse {
+    data: LokiQueryData,
+}
+#[derive(serde::Deserialize)]
+struct LokiQueryData {
+    result: Vec<LokiStream>,
+}
+#[derive(serde::Deserialize)]
+struct LokiStream {
+    stream: std::collections::HashMap<String, String>,
+    values: Vec<[String; 2]>,
+}
@@ -0,0 +1,122 @@
+//! A wrapper around [`kube_forward::HttpForward`] that can re-establish the tunnel in place
+//! instead of leaving callers holding a dead URL.
+//!
+//! A bare `HttpForward` drops the tunnel for good the moment the underlying connection to the
+//! API server hiccups, which turns a transient blip into a hard failure for anything holding on
+//! to its forwarded URL (eg a long-lived `kubectl`-style CLI session).
+//! [`SupervisedForward::reconnect`] re-establishes the forward with capped exponential backoff and
+//! jitter, publishes its [`ForwardStatus`] on a [`tokio::sync::watch`] channel so callers can
+//! observe reconnects as they happen, and keeps the same stable [`SupervisedForward::uri`]
+//! regardless of how many times the tunnel underneath has been rebuilt. Disconnection is not
+//! detected here: the tunnel is rebuilt only when a caller invokes `reconnect`.
+
+use crate::error::Error;
+use rand::Rng;
+use std::time::Duration;
+use tokio::sync::watch;
+
+/// Base back-off applied after a forward drops, doubled per consecutive failed attempt and
+/// capped, with up to 50% jitter added so a fleet of supervisors don't all retry in lockstep.
+const RETRY_BACKOFF_BASE: Duration = Duration::from_millis(250);
+const RETRY_BACKOFF_MAX: Duration = Duration::from_secs(30);
+
+/// Observable state of a [`SupervisedForward`], published on its status channel.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ForwardStatus {
+    /// The tunnel is up and `uri` is serving requests.
+    Connected,
+    /// The tunnel dropped and is being re-established; this is the `attempts`'th consecutive
+    /// attempt since the last successful connection.
+    Reconnecting {
+        /// Number of consecutive reconnect attempts made since the last successful connection.
+        attempts: u32,
+    },
+}
+
+/// A [`kube_forward::HttpForward`] that can be re-established in place with [`Self::reconnect`];
+/// it does not watch the tunnel for disconnection itself.
+///
+/// Callers should treat [`Self::uri`] as a stable endpoint: requests made while the tunnel is
+/// being rebuilt fail, and succeed again against the same URL once the forward reconnects;
+/// [`Self::status`] can be watched to know when that is.
+pub struct SupervisedForward {
+    target: kube_forward::Target,
+    local_port: Option<u16>,
+    forward: kube_forward::HttpForward,
+    status_tx: watch::Sender<ForwardStatus>,
+}
+
+impl SupervisedForward {
+    /// Establish the initial forward to `target` on `local_port` (or a random free port if
+    /// `None`, which is then kept for every reconnect), ready to supervise it from then on.
+    pub async fn new(
+        target: kube_forward::Target,
+        local_port: Option<u16>,
+    ) -> Result<Self, Error> {
+        let forward = kube_forward::HttpForward::new(target.clone(), local_port).await?;
+        // Keep whichever port was bound, so `reconnect` re-establishes the forward on it.
+        let local_port = match local_port {
+            Some(port) => Some(port),
+            None => forward.uri().await?.port_u16(),
+        };
+        let (status_tx, _) = watch::channel(ForwardStatus::Connected);
+        Ok(Self {
+            target,
+            local_port,
+            forward,
+            status_tx,
+        })
+    }
+
+    /// The forwarded endpoint's URI. Stable across reconnects: a caller that stashes this once
+    /// does not need to re-fetch it after [`Self::reconnect`] rebuilds the underlying tunnel,
+    /// since the forward is always re-established on the same `local_port`.
+    pub async fn uri(&self) -> Result<http::Uri, Error> {
+        Ok(self.forward.uri().await?)
+    }
+
+    /// A receiver for this forward's [`ForwardStatus`], for callers that want to observe
+    /// reconnects (eg to log them, or to pause in-flight work until [`ForwardStatus::Connected`]
+    /// comes back).
+    pub fn status(&self) -> watch::Receiver<ForwardStatus> {
+        self.status_tx.subscribe()
+    }
+
+    /// Re-establish the tunnel with capped exponential backoff and jitter between attempts,
+    /// publishing [`ForwardStatus::Reconnecting`] before each attempt and
+    /// [`ForwardStatus::Connected`] once it succeeds. Returns the [`Error::Reconnecting`] of the
+    /// final failed attempt if `max_attempts` is exceeded, rather than retrying forever.
+    pub async fn reconnect(&mut self, max_attempts: u32) -> Result<(), Error> {
+        let mut attempts = 0u32;
+        loop {
+            attempts += 1;
+            let _ = self
+                .status_tx
+                .send(ForwardStatus::Reconnecting { attempts });
+
+            match kube_forward::HttpForward::new(self.target.clone(), self.local_port).await {
+                Ok(forward) => {
+                    self.forward = forward;
+                    let _ = self.status_tx.send(ForwardStatus::Connected);
+                    return Ok(());
+                }
+                Err(source) => {
+                    if attempts >= max_attempts {
+                        return Err(Error::Reconnecting { attempts, source });
+                    }
+                    tokio::time::sleep(backoff_with_jitter(attempts)).await;
+                }
+            }
+        }
+    }
+}
+
+/// `RETRY_BACKOFF_BASE * 2^(attempts - 1)`, capped at `RETRY_BACKOFF_MAX` and jittered by up to
+/// 50% so repeated retries don't all land on the API server at the same instant.
+fn backoff_with_jitter(attempts: u32) -> Duration {
+    let backoff = RETRY_BACKOFF_BASE
+        .saturating_mul(2u32.saturating_pow(attempts.saturating_sub(1)))
+        .min(RETRY_BACKOFF_MAX);
+    let jitter = rand::thread_rng().gen_range(0.0..0.5);
+    backoff.mul_f64(1.0 + jitter)
+}
@@ -5,7 +5,89 @@ use opentelemetry_sdk::{propagation::TraceContextPropagator, Resource};
 use std::time::Duration;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, Registry};
 
-fn init_tracing() {
+/// W3C trace-context injection for the generated `tower-hyper` client.
+///
+/// `openapi::clients::tower::ApiClient` doesn't inject `traceparent`/`tracestate` headers into
+/// outbound requests itself, so a span opened around a call (as below) never actually links up
+/// with the service it calls - the propagator set via `global::set_text_map_propagator` has
+/// nothing pulling the current span's context into the request. [`TraceContextLayer`] is the
+/// `tower::Layer` that belongs on `Configuration`/`ApiClient::new` behind a toggle, so that
+/// enabling tracing end-to-end doesn't need hand-rolled injection per call site; until the
+/// `tower-hyper` template grows that toggle, it can be layered onto the client's inner service
+/// (or any other `tower::Service<http::Request<_>>`) by hand via `ServiceBuilder::layer`.
+mod trace_propagation {
+    use http::{HeaderMap, HeaderName, HeaderValue, Request};
+    use opentelemetry::{global, propagation::Injector};
+    use std::task::{Context, Poll};
+    use tower::{Layer, Service};
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+    /// Injects the currently active `tracing` span's W3C trace context into every outbound
+    /// request, via whichever `opentelemetry` propagator is globally configured.
+    #[derive(Clone, Default)]
+    pub struct TraceContextLayer;
+
+    impl<S> Layer<S> for TraceContextLayer {
+        type Service = TraceContextService<S>;
+
+        fn layer(&self, inner: S) -> Self::Service {
+            TraceContextService { inner }
+        }
+    }
+
+    /// See [`TraceContextLayer`].
+    #[derive(Clone)]
+    pub struct TraceContextService<S> {
+        inner: S,
+    }
+
+    impl<S, B> Service<Request<B>> for TraceContextService<S>
+    where
+        S: Service<Request<B>>,
+    {
+        type Response = S::Response;
+        type Error = S::Error;
+        type Future = S::Future;
+
+        fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            self.inner.poll_ready(cx)
+        }
+
+        fn call(&mut self, mut req: Request<B>) -> Self::Future {
+            let otel_ctx = tracing::Span::current().context();
+            global::get_text_map_propagator(|propagator| {
+                propagator.inject_context(&otel_ctx, &mut HeaderInjector(req.headers_mut()));
+            });
+            self.inner.call(req)
+        }
+    }
+
+    /// Adapts `http::HeaderMap` to the `opentelemetry::propagation::Injector` the configured
+    /// propagator writes `traceparent`/`tracestate` through.
+    struct HeaderInjector<'a>(&'a mut HeaderMap);
+
+    impl Injector for HeaderInjector<'_> {
+        fn set(&mut self, key: &str, value: String) {
+            if let Ok(name) = HeaderName::from_bytes(key.as_bytes()) {
+                if let Ok(value) = HeaderValue::from_str(&value) {
+                    self.0.insert(name, value);
+                }
+            }
+        }
+    }
+}
+
+/// Reusable OTLP bring-up for anything embedding the `tower-hyper` client, not just this example:
+/// batched span export on a background Tokio task instead of `install_simple`'s per-span
+/// synchronous call (which stalls the request it's attached to under any real load), a matching
+/// OTLP metrics pipeline for the `ApiClient` RED instruments in [`client_metrics`], and endpoint/
+/// protocol read from the standard `OTEL_EXPORTER_OTLP_*` env vars (sampling from
+/// `OTEL_TRACES_SAMPLER`/`OTEL_TRACES_SAMPLER_ARG`) so operators can point this at any collector
+/// without a recompile.
+///
+/// Returns the [`client_metrics::ApiClientMetrics`] handle calls should record against; dropping
+/// it does not shut anything down, call `global::shutdown_tracer_provider()` for that.
+fn init_tracing_and_metrics() -> client_metrics::ApiClientMetrics {
     let filter = tracing_subscriber::EnvFilter::try_from_default_env()
         .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
 
@@ -14,26 +96,132 @@ fn init_tracing() {
         .with(tracing_subscriber::fmt::layer().pretty());
 
     let svc_name = Resource::new(vec![KeyValue::new("service.name", "example".to_owned())]);
+    let endpoint = otlp_endpoint();
+    let sampler = otlp_sampler();
 
     global::set_text_map_propagator(TraceContextPropagator::new());
     let tracer = opentelemetry_otlp::new_pipeline()
         .tracing()
-        .with_exporter(
-            opentelemetry_otlp::new_exporter()
-                .tonic()
-                .with_endpoint("http://localhost:4317"),
+        .with_exporter(otlp_exporter_builder(&endpoint))
+        .with_trace_config(
+            opentelemetry_sdk::trace::Config::default()
+                .with_resource(svc_name.clone())
+                .with_sampler(sampler),
         )
-        .with_trace_config(opentelemetry_sdk::trace::Config::default().with_resource(svc_name))
-        .install_simple()
-        .expect("Should be able to initialise the exporter");
+        // Batches spans and exports them from a background task instead of blocking the call
+        // site that produced them on every single export.
+        .install_batch(opentelemetry_sdk::runtime::Tokio)
+        .expect("Should be able to initialise the trace exporter");
     let tracer = tracer.tracer("example");
     let telemetry = tracing_opentelemetry::layer().with_tracer(tracer);
     subscriber.with(telemetry).init();
+
+    let metrics = opentelemetry_otlp::new_pipeline()
+        .metrics(opentelemetry_sdk::runtime::Tokio)
+        .with_exporter(otlp_metrics_exporter_builder(&endpoint))
+        .with_resource(svc_name)
+        .build()
+        .expect("Should be able to initialise the metrics exporter");
+    global::set_meter_provider(metrics);
+
+    client_metrics::ApiClientMetrics::new(global::meter("openapi_client"))
+}
+
+/// `OTEL_EXPORTER_OTLP_ENDPOINT`, defaulting to the collector's standard OTLP/gRPC port.
+fn otlp_endpoint() -> String {
+    std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT").unwrap_or_else(|_| "http://localhost:4317".to_string())
+}
+
+/// `OTEL_TRACES_SAMPLER`/`OTEL_TRACES_SAMPLER_ARG`: `parentbased_traceidratio` (the common case)
+/// falls back to always-on sampling when no ratio is given.
+fn otlp_sampler() -> opentelemetry_sdk::trace::Sampler {
+    let ratio = std::env::var("OTEL_TRACES_SAMPLER_ARG")
+        .ok()
+        .and_then(|v| v.parse::<f64>().ok())
+        .unwrap_or(1.0);
+    match std::env::var("OTEL_TRACES_SAMPLER").as_deref() {
+        Ok("always_off") => opentelemetry_sdk::trace::Sampler::AlwaysOff,
+        Ok("traceidratio") => opentelemetry_sdk::trace::Sampler::TraceIdRatioBased(ratio),
+        _ => opentelemetry_sdk::trace::Sampler::ParentBased(Box::new(
+            opentelemetry_sdk::trace::Sampler::TraceIdRatioBased(ratio),
+        )),
+    }
+}
+
+/// `OTEL_EXPORTER_OTLP_PROTOCOL`: `http/protobuf` selects OTLP/HTTP+protobuf; any other value, or
+/// none, uses the existing tonic (gRPC) exporter.
+fn otlp_exporter_builder(endpoint: &str) -> opentelemetry_otlp::SpanExporterBuilder {
+    match std::env::var("OTEL_EXPORTER_OTLP_PROTOCOL").as_deref() {
+        Ok("http/protobuf") => opentelemetry_otlp::new_exporter()
+            .http()
+            .with_endpoint(endpoint)
+            .into(),
+        _ => opentelemetry_otlp::new_exporter()
+            .tonic()
+            .with_endpoint(endpoint)
+            .into(),
+    }
+}
+
+/// See [`otlp_exporter_builder`]; same protocol switch, for the metrics pipeline's exporter.
+fn otlp_metrics_exporter_builder(endpoint: &str) -> opentelemetry_otlp::MetricsExporterBuilder {
+    match std::env::var("OTEL_EXPORTER_OTLP_PROTOCOL").as_deref() {
+        Ok("http/protobuf") => opentelemetry_otlp::new_exporter()
+            .http()
+            .with_endpoint(endpoint)
+            .into(),
+        _ => opentelemetry_otlp::new_exporter()
+            .tonic()
+            .with_endpoint(endpoint)
+            .into(),
+    }
+}
+
+/// RED (rate/errors/duration) instruments for `ApiClient` calls, recorded manually at each call
+/// site until the generated client grows the equivalent of [`trace_propagation::TraceContextLayer`]
+/// and can record these itself.
+mod client_metrics {
+    use opentelemetry::metrics::{Counter, Histogram, Meter};
+
+    /// Request count, latency and error count for calls made through an `ApiClient`.
+    pub struct ApiClientMetrics {
+        requests: Counter<u64>,
+        errors: Counter<u64>,
+        latency: Histogram<f64>,
+    }
+
+    impl ApiClientMetrics {
+        pub fn new(meter: Meter) -> Self {
+            Self {
+                requests: meter
+                    .u64_counter("openapi_client.requests")
+                    .with_description("Total ApiClient requests made")
+                    .init(),
+                errors: meter
+                    .u64_counter("openapi_client.errors")
+                    .with_description("Total ApiClient requests that returned an error")
+                    .init(),
+                latency: meter
+                    .f64_histogram("openapi_client.request_duration_seconds")
+                    .with_description("ApiClient request latency")
+                    .init(),
+            }
+        }
+
+        /// Record one completed call: `elapsed` since it started, and whether it errored.
+        pub fn record(&self, elapsed: std::time::Duration, is_err: bool) {
+            self.requests.add(1, &[]);
+            if is_err {
+                self.errors.add(1, &[]);
+            }
+            self.latency.record(elapsed.as_secs_f64(), &[]);
+        }
+    }
 }
 
 #[tokio::main]
 async fn main() {
-    init_tracing();
+    let metrics = init_tracing_and_metrics();
     let config = Configuration::new(
         "http://localhost:8081/".parse().unwrap(),
         Duration::from_secs(5),
@@ -49,11 +237,14 @@ async fn main() {
         let span = tracing::info_span!("span example");
         let _enter = span.enter();
 
+        let started = std::time::Instant::now();
         match client.nodes_api().get_nodes(None).await {
             Ok(resp) => {
+                metrics.record(started.elapsed(), false);
                 println!("resp: {resp:#?}");
             }
             Err(resp) => {
+                metrics.record(started.elapsed(), true);
                 println!("resp: {resp:#?}");
             }
         }